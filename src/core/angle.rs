@@ -2,14 +2,109 @@ use core::f64;
 
 use uom::si::angle::{degree, radian};
 
-use super::IntoF64;
+use super::{IntoF64, intof64::hash_key};
 
 /// A physical angle (i.e. a distance).
 ///
 /// Angle exists to remove ambiguity about angle units, which are not supported by default by
 /// major CAD kernels.
+///
+/// Constructing an `Angle` keeps the raw value it was given, so `-90.deg()` and `270.deg()` are not
+/// equal. Use the methods of `AngleExt` to bring angles into a common range before comparing them.
 pub type Angle = uom::si::f64::Angle;
 
+/// Import this trait to use additional methods on `Angle`s.
+///
+/// The trigonometric functions `sin()`, `cos()` and `tan()` are already provided by `Angle`
+/// itself. They return a dimensionless ratio that converts into an `f64` with `.into()`.
+///
+/// ```rust
+/// use anvil::IntoAngle;
+/// use approx::assert_relative_eq;
+///
+/// let sin: f64 = 90.deg().sin().into();
+/// assert_relative_eq!(sin, 1.);
+/// ```
+pub trait AngleExt {
+    /// Construct the `Angle` of the vector `(x, y)` in the range `(-π, π]`.
+    ///
+    /// This is the four quadrant arctangent of `y / x`.
+    ///
+    /// ```rust
+    /// use anvil::{Angle, AngleExt, IntoAngle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// assert_relative_eq!(Angle::from_atan2(1., 1.).value, 45.deg().value);
+    /// assert_relative_eq!(Angle::from_atan2(0., -1.).value, 180.deg().value);
+    /// ```
+    fn from_atan2(y: f64, x: f64) -> Angle;
+
+    /// Return an equivalent `Angle` in the range `[0, 2π)`.
+    ///
+    /// ```rust
+    /// use anvil::{AngleExt, IntoAngle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// assert_relative_eq!((-90).deg().normalized().value, 270.deg().value);
+    /// assert_relative_eq!(450.deg().normalized().value, 90.deg().value);
+    /// assert_relative_eq!(360.deg().normalized().value, 0.deg().value);
+    /// ```
+    fn normalized(&self) -> Angle;
+
+    /// Return an equivalent `Angle` in the range `(-π, π]`.
+    ///
+    /// ```rust
+    /// use anvil::{AngleExt, IntoAngle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// assert_relative_eq!(270.deg().normalized_signed().value, (-90).deg().value);
+    /// assert_relative_eq!(180.deg().normalized_signed().value, 180.deg().value);
+    /// assert_relative_eq!((-180).deg().normalized_signed().value, 180.deg().value);
+    /// ```
+    fn normalized_signed(&self) -> Angle;
+
+    /// Return a key based on the exact bit pattern of this `Angle` in radians.
+    ///
+    /// `Angle`s can not implement `Hash` themselves. The key can be used instead, for example in a
+    /// `HashMap`. Equal `Angle`s have equal keys, but angles that only match after normalization
+    /// do not.
+    ///
+    /// ```rust
+    /// use anvil::{AngleExt, IntoAngle};
+    ///
+    /// assert_eq!(90.deg().hash_key(), 90.deg().hash_key());
+    /// assert_ne!(90.deg().hash_key(), 450.deg().hash_key());
+    /// ```
+    fn hash_key(&self) -> u64;
+}
+impl AngleExt for Angle {
+    fn from_atan2(y: f64, x: f64) -> Angle {
+        Angle::new::<radian>(y.atan2(x))
+    }
+
+    fn normalized(&self) -> Angle {
+        let radians = self.get::<radian>().rem_euclid(f64::consts::TAU);
+        if radians >= f64::consts::TAU {
+            // rem_euclid can round up to TAU for tiny negative inputs
+            return Angle::new::<radian>(0.);
+        }
+        Angle::new::<radian>(radians)
+    }
+
+    fn normalized_signed(&self) -> Angle {
+        let normalized = self.normalized();
+        if normalized > Angle::HALF_TURN {
+            normalized - Angle::FULL_TURN
+        } else {
+            normalized
+        }
+    }
+
+    fn hash_key(&self) -> u64 {
+        hash_key(self.get::<radian>())
+    }
+}
+
 /// Import this trait to easily convert numbers into `Angle`s.
 ///
 /// ```rust
@@ -62,6 +157,7 @@ impl IntoAngle for f64 {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn add() {
@@ -79,8 +175,56 @@ mod tests {
         assert_eq!(4. * 0.2.rad(), 0.8.rad());
     }
 
+    #[test]
+    fn sum() {
+        let angles = [10.deg(), 20.deg(), 60.deg()];
+        assert_relative_eq!(angles.iter().copied().sum::<Angle>().value, 90.deg().value);
+    }
+
+    #[test]
+    fn trigonometric_functions() {
+        assert_relative_eq!(90.deg().sin().value, 1.);
+        assert_relative_eq!(60.deg().cos().value, 0.5);
+        assert_relative_eq!(45.deg().tan().value, 1.);
+    }
+
+    #[test]
+    fn from_atan2() {
+        assert_relative_eq!(Angle::from_atan2(1., 0.).value, 90.deg().value);
+        assert_relative_eq!(Angle::from_atan2(-1., -1.).value, (-135).deg().value);
+        assert_eq!(Angle::from_atan2(0., 0.), 0.rad());
+    }
+
+    #[test]
+    fn hash_key_of_zero() {
+        assert_eq!(0.rad().hash_key(), (-0.).rad().hash_key());
+    }
+
     #[test]
     fn divide_with_f64() {
         assert_eq!(6.rad() / 2., 3.rad());
     }
+
+    #[test]
+    fn normalized_negative() {
+        assert_relative_eq!((-90).deg().normalized().value, 270.deg().value);
+    }
+
+    #[test]
+    fn normalized_signed_above_half_turn() {
+        assert_relative_eq!(270.deg().normalized_signed().value, (-90).deg().value);
+    }
+
+    #[test]
+    fn normalized_tiny_negative() {
+        assert!((-1e-20).rad().normalized() < Angle::FULL_TURN);
+    }
+
+    #[test]
+    fn normalized_independent_of_unit() {
+        let from_degrees = 370.deg().normalized();
+        let from_radians = 370_f64.to_radians().rad().normalized();
+        assert_relative_eq!(from_degrees.get::<degree>(), 10., epsilon = 1e-12);
+        assert_relative_eq!(from_degrees.value, from_radians.value, epsilon = 1e-12);
+    }
 }
@@ -93,6 +93,78 @@ impl<const DIM: usize> Axis<DIM> {
     pub fn point_at(&self, distance: Length) -> Point<DIM> {
         self.origin + self.direction * distance
     }
+
+    /// Return a clone of this `Axis` pointing in the opposite direction.
+    ///
+    /// The origin of the `Axis` is kept.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoLength, dir, point};
+    ///
+    /// // for 2d
+    /// assert_eq!(Axis::<2>::x().reverse(), Axis::<2>::neg_x());
+    ///
+    /// // for 3d
+    /// let axis = Axis::<3>::new(point!(1.m(), 2.m(), 3.m()), dir!(1, 1, 0));
+    /// assert_eq!(
+    ///     axis.reverse(),
+    ///     Axis::<3>::new(point!(1.m(), 2.m(), 3.m()), dir!(-1, -1, 0))
+    /// );
+    /// ```
+    pub fn reverse(&self) -> Self {
+        Self::new(self.origin, -self.direction)
+    }
+
+    /// Return the `Point` on this `Axis` closest to another `Point`.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoLength, dir, point};
+    ///
+    /// // for 2d
+    /// assert_eq!(Axis::<2>::x().project(point!(2.m(), 5.m())), point!(2.m(), 0.m()));
+    /// assert_eq!(Axis::<2>::neg_y().project(point!(2.m(), 5.m())), point!(0.m(), 5.m()));
+    ///
+    /// // for 3d
+    /// let axis = Axis::<3>::new(point!(1.m(), 2.m(), 3.m()), dir!(0, 0, 1));
+    /// assert_eq!(axis.project(point!(5.m(), 5.m(), 5.m())), point!(1.m(), 2.m(), 5.m()));
+    /// ```
+    pub fn project(&self, point: Point<DIM>) -> Point<DIM> {
+        self.point_at(self.offset_along(point))
+    }
+
+    /// Return the perpendicular distance between a `Point` and this `Axis`.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoLength, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // for 2d
+    /// assert_eq!(Axis::<2>::x().distance_to_point(point!(0.m(), 3.m())), 3.m());
+    /// assert_eq!(Axis::<2>::x().distance_to_point(point!(5.m(), 0.m())), 0.m());
+    ///
+    /// // for 3d
+    /// let axis = Axis::<3>::new(point!(1.m(), 1.m(), 0.m()), dir!(1, 1, 0));
+    /// assert_relative_eq!(axis.distance_to_point(point!(0.m(), 2.m(), 4.m())).value, 18_f64.sqrt());
+    /// assert_relative_eq!(
+    ///     axis.distance_to_point(point!(3.m(), 3.m(), 0.m())).value,
+    ///     0.,
+    ///     epsilon = 1e-9
+    /// );
+    /// ```
+    pub fn distance_to_point(&self, point: Point<DIM>) -> Length {
+        point.distance_to(self.project(point))
+    }
+
+    /// Return the signed distance along the `Axis` from its origin to the foot of the
+    /// perpendicular from a `Point`.
+    fn offset_along(&self, point: Point<DIM>) -> Length {
+        (point - self.origin)
+            .0
+            .into_iter()
+            .zip(self.direction.0)
+            .map(|(coordinate, component)| coordinate * component)
+            .sum()
+    }
 }
 impl<const DIM: usize> From<(Point<DIM>, Dir<DIM>)> for Axis<DIM> {
     fn from((origin, direction): (Point<DIM>, Dir<DIM>)) -> Self {
@@ -179,6 +251,63 @@ impl Axis<3> {
         Self::new(point!(0, 0, 0), dir!(0, 0, -1))
     }
 
+    /// Return the pair of `Point`s on this `Axis<3>` and another that lie closest to each other.
+    ///
+    /// If the two axes are parallel, the origin of this axis and its counterpart on the other axis
+    /// are returned.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoLength, dir, point};
+    ///
+    /// let axis1 = Axis::<3>::x();
+    /// let axis2 = Axis::<3>::new(point!(3.m(), 1.m(), 2.m()), dir!(0, 1, 0));
+    /// assert_eq!(
+    ///     axis1.closest_points(axis2),
+    ///     (point!(3.m(), 0.m(), 0.m()), point!(3.m(), 0.m(), 2.m()))
+    /// );
+    ///
+    /// let parallel = Axis::<3>::new(point!(5.m(), 3.m(), 0.m()), dir!(-1, 0, 0));
+    /// assert_eq!(
+    ///     axis1.closest_points(parallel),
+    ///     (point!(0, 0, 0), point!(0.m(), 3.m(), 0.m()))
+    /// );
+    /// ```
+    pub fn closest_points(&self, other: Self) -> (Point<3>, Point<3>) {
+        let cos = self.direction.dot(other.direction);
+        let denominator = 1. - cos.powi(2);
+
+        let offset_self = -self.offset_along(other.origin);
+        let offset_other = other.offset_along(self.origin);
+
+        let lines_are_parallel = denominator < 1e-9;
+        if lines_are_parallel {
+            return (self.origin, other.point_at(offset_other));
+        }
+
+        let distance_self = (cos * offset_other - offset_self) / denominator;
+        let distance_other = (offset_other - cos * offset_self) / denominator;
+        (self.point_at(distance_self), other.point_at(distance_other))
+    }
+
+    /// Return the shortest distance between this `Axis<3>` and another.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoLength, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let axis = Axis::<3>::new(point!(0.m(), 0.m(), 2.m()), dir!(0, 1, 0));
+    /// assert_relative_eq!(Axis::<3>::x().distance_to(axis).value, 2.m().value);
+    ///
+    /// let parallel = Axis::<3>::new(point!(0.m(), 3.m(), 4.m()), dir!(0, 0, 1));
+    /// assert_relative_eq!(Axis::<3>::z().distance_to(parallel).value, 3.m().value);
+    ///
+    /// assert_relative_eq!(Axis::<3>::x().distance_to(Axis::<3>::y()).value, 0.);
+    /// ```
+    pub fn distance_to(&self, other: Self) -> Length {
+        let (point, other_point) = self.closest_points(other);
+        point.distance_to(other_point)
+    }
+
     pub(crate) fn to_occt_ax1(self) -> UniquePtr<ffi::gp_Ax1> {
         ffi::gp_Ax1_ctor(&self.origin.to_occt_point(), &self.direction.to_occt_dir())
     }
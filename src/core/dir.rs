@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Mul, Neg, Sub};
 
 use approx::{AbsDiffEq, RelativeEq};
 use cxx::UniquePtr;
@@ -6,7 +6,8 @@ use iter_fixed::IntoIteratorFixed;
 use opencascade_sys::ffi;
 use uom::si::angle::radian;
 
-use crate::{Angle, Error, Length, Point};
+use super::intof64::hash_key;
+use crate::{Angle, AngleExt, Error, Length, Point};
 
 /// A direction in space with a length of 1.
 ///
@@ -26,7 +27,7 @@ use crate::{Angle, Error, Length, Point};
 /// assert_eq!(dir!(3, 4, 5), Dir::try_from([3., 4., 5.]).unwrap());
 /// ```
 #[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
-pub struct Dir<const DIM: usize>([f64; DIM]);
+pub struct Dir<const DIM: usize>(pub(crate) [f64; DIM]);
 impl<const DIM: usize> Dir<DIM> {
     /// Construct a `Dir` from the directional components.
     ///
@@ -65,6 +66,46 @@ impl<const DIM: usize> Dir<DIM> {
     pub fn dot(&self, other: Self) -> f64 {
         self.0.into_iter().zip(other.0).map(|(a, b)| a * b).sum()
     }
+
+    /// Return a key based on the exact bit patterns of the components of this `Dir`.
+    ///
+    /// `Dir`s can not implement `Hash` as their components are floating point numbers. The key can
+    /// be used instead, for example in a `HashMap`. Equal `Dir`s have equal keys.
+    ///
+    /// ```rust
+    /// use anvil::dir;
+    ///
+    /// assert_eq!(dir!(1, 1, 0).hash_key(), dir!(2, 2, 0).hash_key());
+    /// assert_ne!(dir!(1, 1, 0).hash_key(), dir!(1, 0, 0).hash_key());
+    /// ```
+    pub fn hash_key(&self) -> [u64; DIM] {
+        self.0.map(hash_key)
+    }
+
+    /// Return this `Dir` reflected about a normal `Dir` of the same dimension.
+    ///
+    /// Returns an Error::ZeroVector if the reflected vector is zero.
+    ///
+    /// ```rust
+    /// use anvil::dir;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // for 2d
+    /// assert_relative_eq!(dir!(1, -1).reflect(dir!(0, 1)).unwrap(), dir!(1, 1));
+    ///
+    /// // for 3d
+    /// assert_relative_eq!(dir!(1, 0, -1).reflect(dir!(0, 0, 1)).unwrap(), dir!(1, 0, 1));
+    /// ```
+    pub fn reflect(&self, normal: Self) -> Result<Self, Error> {
+        let dot = self.dot(normal);
+        Self::try_from(
+            self.0
+                .into_iter_fixed()
+                .zip(normal.0)
+                .map(|(d, n)| d - 2. * dot * n)
+                .collect(),
+        )
+    }
 }
 
 impl Dir<2> {
@@ -92,7 +133,7 @@ impl Dir<2> {
     /// assert_eq!(dir!(1, -1).angle(), 315.deg());
     /// ```
     pub fn angle(&self) -> Angle {
-        let angle = Angle::new::<radian>(self.y().atan2(self.x()));
+        let angle = Angle::from_atan2(self.y(), self.x());
         if angle.get::<radian>() < 0. {
             angle + Angle::FULL_TURN
         } else {
@@ -139,6 +180,35 @@ impl Dir<3> {
         ])
     }
 
+    /// Return this `Dir<3>` rotated counter clockwise around an axis `Dir<3>` by an `Angle`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoAngle, dir};
+    /// use approx::assert_relative_eq;
+    ///
+    /// assert_relative_eq!(dir!(1, 0, 0).rotate_around(dir!(0, 0, 1), 90.deg()), dir!(0, 1, 0));
+    /// assert_relative_eq!(dir!(0, 1, 0).rotate_around(dir!(1, 0, 0), 90.deg()), dir!(0, 0, 1));
+    /// assert_relative_eq!(
+    ///     dir!(1, 0, 0).rotate_around(dir!(1, 1, 1), 120.deg()),
+    ///     dir!(0, 1, 0),
+    ///     epsilon = 1e-9
+    /// );
+    /// assert_relative_eq!(dir!(1, 0, 0).rotate_around(dir!(1, 0, 0), 45.deg()), dir!(1, 0, 0));
+    /// ```
+    pub fn rotate_around(&self, axis: Self, angle: Angle) -> Self {
+        let (sin, cos) = angle.get::<radian>().sin_cos();
+        let cross = axis.cross(*self);
+        let dot = axis.dot(*self);
+        Self(
+            self.0
+                .into_iter_fixed()
+                .zip(cross.0)
+                .zip(axis.0)
+                .map(|((v, c), k)| v * cos + c * sin + k * dot * (1. - cos))
+                .collect(),
+        )
+    }
+
     pub(crate) fn to_occt_dir(self) -> UniquePtr<ffi::gp_Dir> {
         ffi::gp_Dir_ctor(self.x(), self.y(), self.z())
     }
@@ -220,6 +290,26 @@ impl<const DIM: usize> Sub<Self> for Dir<DIM> {
     }
 }
 
+impl<const DIM: usize> Neg for Dir<DIM> {
+    type Output = Self;
+    /// Return the `Dir` pointing in the opposite direction.
+    ///
+    /// ```rust
+    /// use anvil::dir;
+    ///
+    /// // for 2d
+    /// assert_eq!(-dir!(1, 0), dir!(-1, 0));
+    /// assert_eq!(-(-dir!(3, 4)), dir!(3, 4));
+    ///
+    /// // for 3d
+    /// assert_eq!(-dir!(1, 0, 0), dir!(-1, 0, 0));
+    /// assert_eq!(-(-dir!(1, 2, 3)), dir!(1, 2, 3));
+    /// ```
+    fn neg(self) -> Self {
+        Self(self.0.into_iter_fixed().map(|n| -n).collect())
+    }
+}
+
 impl<const DIM: usize> Mul<Length> for Dir<DIM> {
     type Output = Point<DIM>;
     /// Multiply this `Dir` with a `Length` to get a `Point`.
@@ -244,6 +334,18 @@ impl<const DIM: usize> Mul<Length> for Dir<DIM> {
     }
 }
 
+/// `Dir`s are compared component-wise by the absolute difference of their components, so
+/// comparisons involving components of or close to zero are well-defined.
+///
+/// ```rust
+/// use anvil::{Dir, dir};
+/// use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+///
+/// let almost_x = Dir::<2>::try_from([1., 0.00000001]).unwrap();
+/// assert_abs_diff_eq!(dir!(1, 0), almost_x, epsilon = 1e-6);
+/// assert_relative_eq!(dir!(1, 0), almost_x, epsilon = 1e-6);
+/// assert_relative_ne!(dir!(1, 0), dir!(1, 1), epsilon = 1e-6);
+/// ```
 impl<const DIM: usize> AbsDiffEq for Dir<DIM> {
     type Epsilon = f64;
     fn default_epsilon() -> Self::Epsilon {
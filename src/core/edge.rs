@@ -2,10 +2,13 @@ use core::f64;
 
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
-use uom::si::angle::degree;
+use uom::si::angle::{degree, radian};
 use uom::si::length::meter;
 
-use crate::{Angle, Axis, Dir, Error, Length, Plane, Point};
+use crate::{Angle, AngleExt, Axis, Dir, Error, IntoLength, Length, Plane, Point, Transform};
+
+/// The maximum number of points of a `Bezier` edge, limited by the maximum degree of 25 in OCCT.
+const MAX_BEZIER_POINTS: usize = 26;
 
 /// A one-dimensional object in two-dimensional space.
 #[derive(Debug, PartialEq, Clone)]
@@ -13,12 +16,28 @@ pub enum Edge {
     /// A circle section defined by the start point, a mid point and the end point.
     Arc(Point<2>, Point<2>, Point<2>),
 
+    /// A Bézier curve defined by the start point, any number of control points and the end point.
+    ///
+    /// A Bézier curve with more than 26 points in total can not be constructed.
+    Bezier(Vec<Point<2>>),
+
+    /// A full circle defined by its center and radius.
+    ///
+    /// The circle starts and ends at the point with the largest x-coordinate and runs counter
+    /// clockwise.
+    Circle(Point<2>, Length),
+
     /// A line between two points.
     Line(Point<2>, Point<2>),
+
+    /// A smooth spline that passes through all of its points.
+    Spline(Vec<Point<2>>),
 }
 impl Edge {
     /// Return the starting point of the edge.
     ///
+    /// A `Bezier` or `Spline` without any points is degenerate and starts at the origin.
+    ///
     /// ```rust
     /// use anvil::{Edge, IntoLength, point};
     /// use uom::si::length::meter;
@@ -29,11 +48,17 @@ impl Edge {
     pub fn start(&self) -> Point<2> {
         match self {
             Self::Arc(start, _, _) => *start,
+            Self::Bezier(points) | Self::Spline(points) => {
+                points.first().copied().unwrap_or_else(Point::origin)
+            }
+            Self::Circle(center, radius) => Point::<2>::new([center.x() + *radius, center.y()]),
             Self::Line(start, _) => *start,
         }
     }
     /// Return the ending point of the edge.
     ///
+    /// A `Bezier` or `Spline` without any points is degenerate and ends at the origin.
+    ///
     /// ```rust
     /// use anvil::{Edge, IntoLength, point};
     /// use uom::si::length::meter;
@@ -44,6 +69,10 @@ impl Edge {
     pub fn end(&self) -> Point<2> {
         match self {
             Self::Arc(_, _, end) => *end,
+            Self::Bezier(points) | Self::Spline(points) => {
+                points.last().copied().unwrap_or_else(Point::origin)
+            }
+            Self::Circle(_, _) => self.start(),
             Self::Line(_, end) => *end,
         }
     }
@@ -53,56 +82,30 @@ impl Edge {
     /// ```rust
     /// use core::f64;
     /// use anvil::{Edge, IntoLength, point};
-    /// use uom::si::length::meter;
+    /// use approx::assert_relative_eq;
     ///
     /// let line = Edge::Line(point!(1.m(), 0.m()), point!(1.m(), 2.m()));
     /// assert_eq!(line.len(), 2.m());
     ///
     /// let arc = Edge::Arc(point!(-1.m(), 0.m()), point!(0.m(), 1.m()), point!(1.m(), 0.m()));
-    /// assert_eq!(arc.len(), f64::consts::PI.m());
+    /// assert_relative_eq!(arc.len().value, f64::consts::PI);
     /// ```
     pub fn len(&self) -> Length {
         match self {
-            Self::Arc(start, mid, end) => {
-                // Works for now but needs to be refactored in the future
-                let (x1, y1) = (start.x().get::<meter>(), start.y().get::<meter>());
-                let (x2, y2) = (mid.x().get::<meter>(), mid.y().get::<meter>());
-                let (x3, y3) = (end.x().get::<meter>(), end.y().get::<meter>());
-
-                let b = (x1.powi(2) + y1.powi(2)) * (y3 - y2)
-                    + (x2.powi(2) + y2.powi(2)) * (y1 - y3)
-                    + (x3.powi(2) + y3.powi(2)) * (y2 - y1);
-                let c = (x1.powi(2) + y1.powi(2)) * (x2 - x3)
-                    + (x2.powi(2) + y2.powi(2)) * (x3 - x1)
-                    + (x3.powi(2) + y3.powi(2)) * (x1 - x2);
-
-                let denom = 2.0 * (x1 * (y2 - y3) + x2 * (y3 - y1) + x3 * (y1 - y2));
-                if denom.abs() < f64::EPSILON {
-                    return Length::new::<meter>(0.);
+            Self::Arc(_, _, _) | Self::Bezier(_) | Self::Spline(_) => {
+                match self.to_occt(Plane::xy()) {
+                    Some(edge) => {
+                        let mut gprops = ffi::GProp_GProps_ctor();
+                        ffi::BRepGProp_LinearProperties(
+                            ffi::cast_edge_to_shape(&edge),
+                            gprops.pin_mut(),
+                        );
+                        Length::new::<meter>(gprops.Mass())
+                    }
+                    None => Length::new::<meter>(0.),
                 }
-                let cx = -b / denom;
-                let cy = -c / denom;
-
-                let r = f64::sqrt((x1 - cx).powi(2) + (y1 - cy).powi(2));
-
-                let v1 = ((x1 - cx), (y1 - cy));
-                let v2 = ((x3 - cx), (y3 - cy));
-
-                let dot = v1.0 * v2.0 + v1.1 * v2.1;
-                let det = v1.0 * v2.1 - v1.1 * v2.0;
-                let mut angle = det.atan2(dot).abs();
-
-                let is_mid_on_arc = {
-                    let cross1 = (x2 - x1) * (y3 - y1) - (y2 - y1) * (x3 - x1);
-                    let cross2 = (cx - x1) * (y3 - y1) - (cy - y1) * (x3 - x1);
-                    cross1 * cross2 >= 0.0
-                };
-                if !is_mid_on_arc {
-                    angle = f64::consts::TAU - angle;
-                }
-
-                Length::new::<meter>(r * angle)
             }
+            Self::Circle(_, radius) => *radius * f64::consts::TAU,
             Self::Line(start, end) => {
                 let diff = *start - *end;
                 Length::new::<meter>(f64::sqrt(
@@ -120,34 +123,188 @@ impl Edge {
     ///
     /// let line = Edge::Line(point!(0, 0), point!(1.m(), 2.m()));
     /// assert_eq!(line.end_direction(), Ok(dir!(1, 2)));
+    ///
+    /// let bezier = Edge::Bezier(vec![point!(0, 0), point!(1.m(), 0.m()), point!(1.m(), 1.m())]);
+    /// assert_eq!(bezier.end_direction(), Ok(dir!(0, 1)));
     /// ```
     pub fn end_direction(&self) -> Result<Dir<2>, Error> {
         match self {
-            Self::Arc(start, interior, end) => {
-                let (center, _) = arc_center_radius(*start, *interior, *end)?;
-
-                let start_angle = arc_point_angle_on_unit_circle(center, *start);
-                let interior_angle = arc_point_angle_on_unit_circle(center, *interior);
-                let end_angle = arc_point_angle_on_unit_circle(center, *end);
-
-                let arc_is_clockwise = (end_angle > start_angle || start_angle > interior_angle)
-                    && interior_angle > end_angle;
-
-                if arc_is_clockwise {
-                    Ok(Dir::from(end_angle - Angle::new::<degree>(90.)))
-                } else {
-                    Ok(Dir::from(end_angle + Angle::new::<degree>(90.)))
+            Self::Arc(_, _, _) => self.tangent_at(1.),
+            Self::Bezier(points) => {
+                let end = self.end();
+                match points.iter().rev().find(|point| **point != end) {
+                    Some(previous) => end.direction_from(*previous),
+                    None => Err(Error::ZeroVector),
                 }
             }
+            Self::Circle(_, _) => self.tangent_at(1.),
             Self::Line(start, end) => Dir::<2>::try_from([
                 (*end - *start).x().get::<meter>(),
                 (*end - *start).y().get::<meter>(),
             ]),
+            Self::Spline(_) => self.tangent_at(1.),
+        }
+    }
+
+    /// Return the `Point` on this `Edge` at a normalized parameter between 0 and 1.
+    ///
+    /// A parameter of 0 returns the start and 1 the end of the `Edge`. Lines are interpolated
+    /// linearly and arcs by their swept angle.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, IntoLength, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let line = Edge::Line(point!(0, 0), point!(2.m(), 4.m()));
+    /// assert_relative_eq!(line.point_at(0.5), point!(1.m(), 2.m()));
+    ///
+    /// let arc = Edge::Arc(point!(-1.m(), 0.m()), point!(0.m(), 1.m()), point!(1.m(), 0.m()));
+    /// assert_relative_eq!(arc.point_at(0.5), point!(0.m(), 1.m()), epsilon = 1e-9);
+    /// ```
+    pub fn point_at(&self, t: f64) -> Point<2> {
+        match self {
+            Self::Arc(start, interior, end) => match arc_center_radius(*start, *interior, *end) {
+                Ok((center, radius)) => {
+                    let (start_angle, sweep) =
+                        arc_start_angle_sweep(center, *start, *interior, *end);
+                    center + Dir::from(start_angle + sweep * t) * radius
+                }
+                Err(_) => *start,
+            },
+            Self::Bezier(points) => de_casteljau(points, t),
+            Self::Circle(center, radius) => *center + Dir::from(Angle::FULL_TURN * t) * *radius,
+            Self::Line(start, end) => start.lerp(*end, t),
+            Self::Spline(_) => match self.occt_points_at(&[t]) {
+                Some(points) => points[0],
+                None => self.start(),
+            },
+        }
+    }
+
+    /// Return the direction this `Edge` is pointing to at a normalized parameter between 0 and 1.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, IntoLength, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let line = Edge::Line(point!(0, 0), point!(2.m(), 4.m()));
+    /// assert_relative_eq!(line.tangent_at(0.5).unwrap(), dir!(1, 2));
+    ///
+    /// let arc = Edge::Arc(point!(-1.m(), 0.m()), point!(0.m(), 1.m()), point!(1.m(), 0.m()));
+    /// assert_relative_eq!(arc.tangent_at(0.5).unwrap(), dir!(1, 0), epsilon = 1e-9);
+    /// ```
+    pub fn tangent_at(&self, t: f64) -> Result<Dir<2>, Error> {
+        match self {
+            Self::Arc(start, interior, end) => {
+                let (center, _) = arc_center_radius(*start, *interior, *end)?;
+                let (start_angle, sweep) = arc_start_angle_sweep(center, *start, *interior, *end);
+                let quarter_turn = Angle::new::<degree>(90.);
+                if sweep.get::<radian>() < 0. {
+                    Ok(Dir::from(start_angle + sweep * t - quarter_turn))
+                } else {
+                    Ok(Dir::from(start_angle + sweep * t + quarter_turn))
+                }
+            }
+            Self::Bezier(points) => {
+                let derivative_points: Vec<Point<2>> =
+                    points.windows(2).map(|pair| pair[1] - pair[0]).collect();
+                if derivative_points.is_empty() {
+                    return Err(Error::ZeroVector);
+                }
+                let derivative = de_casteljau(&derivative_points, t);
+                Dir::<2>::try_from([derivative.x().get::<meter>(), derivative.y().get::<meter>()])
+            }
+            Self::Circle(_, _) => Ok(Dir::from(Angle::FULL_TURN * t + Angle::new::<degree>(90.))),
+            Self::Line(_, _) => self.end_direction(),
+            Self::Spline(_) => {
+                let step = 1e-6;
+                let (t1, t2) = if t + step <= 1. {
+                    (t, t + step)
+                } else {
+                    (t - step, t)
+                };
+                let points = self.occt_points_at(&[t1, t2]).ok_or(Error::ZeroVector)?;
+                points[1].direction_from(points[0])
+            }
+        }
+    }
+
+    /// Split this `Edge` at a normalized parameter between 0 and 1 into two `Edge`s.
+    ///
+    /// The first `Edge` runs from the start to the split point and the second from the split
+    /// point to the end, so that together they trace the original `Edge`. Circles are split into
+    /// two arcs that meet at the start point of the circle. Splines are approximated by two new
+    /// splines through points sampled on the original. Parameters outside of the range from 0 to 1
+    /// are clamped. A `Bezier` or `Spline` without any points is split into two copies of itself.
+    /// Splitting an `Arc` or `Circle` at its start or end yields the original `Edge` and a
+    /// zero-length `Line` at that point, since an arc can not be empty.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, IntoLength, point};
+    ///
+    /// let line = Edge::Line(point!(0, 0), point!(4.m(), 0.m()));
+    /// assert_eq!(
+    ///     line.split_at(0.25),
+    ///     (
+    ///         Edge::Line(point!(0, 0), point!(1.m(), 0.m())),
+    ///         Edge::Line(point!(1.m(), 0.m()), point!(4.m(), 0.m())),
+    ///     )
+    /// );
+    /// ```
+    pub fn split_at(&self, t: f64) -> (Self, Self) {
+        let t = t.clamp(0., 1.);
+        let split_point = self.point_at(t);
+        match self {
+            Self::Bezier(points) | Self::Spline(points) if points.is_empty() => {
+                (self.clone(), self.clone())
+            }
+            Self::Arc(_, _, _) | Self::Circle(_, _) if t <= f64::EPSILON => {
+                (Self::Line(self.start(), self.start()), self.clone())
+            }
+            Self::Arc(_, _, _) | Self::Circle(_, _) if t >= 1. - f64::EPSILON => {
+                (self.clone(), Self::Line(self.end(), self.end()))
+            }
+            Self::Arc(start, _, end) => (
+                Self::Arc(*start, self.point_at(t / 2.), split_point),
+                Self::Arc(split_point, self.point_at((1. + t) / 2.), *end),
+            ),
+            Self::Bezier(points) => {
+                let mut first = vec![self.start()];
+                let mut second = vec![self.end()];
+                let mut points = points.clone();
+                while points.len() > 1 {
+                    points = points
+                        .windows(2)
+                        .map(|pair| pair[0].lerp(pair[1], t))
+                        .collect();
+                    first.push(points[0]);
+                    second.push(*points.last().expect("at least one point remains"));
+                }
+                second.reverse();
+                (Self::Bezier(first), Self::Bezier(second))
+            }
+            Self::Circle(_, _) => (
+                Self::Arc(self.start(), self.point_at(t / 2.), split_point),
+                Self::Arc(split_point, self.point_at((1. + t) / 2.), self.start()),
+            ),
+            Self::Line(start, end) => (
+                Self::Line(*start, split_point),
+                Self::Line(split_point, *end),
+            ),
+            Self::Spline(points) => {
+                let samples = points.len().max(2);
+                let sample = |from: f64, to: f64| -> Vec<Point<2>> {
+                    (0..=samples)
+                        .map(|i| self.point_at(from + (to - from) * i as f64 / samples as f64))
+                        .collect()
+                };
+                (Self::Spline(sample(0., t)), Self::Spline(sample(t, 1.)))
+            }
         }
     }
 
     pub(crate) fn to_occt(&self, plane: Plane) -> Option<UniquePtr<ffi::TopoDS_Edge>> {
-        if self.len() == Length::new::<meter>(0.) {
+        if self.is_degenerate() {
             return None;
         }
         match self {
@@ -167,6 +324,42 @@ impl Edge {
                     .Edge(),
                 ))
             }
+            Self::Bezier(points) => {
+                let mut poles = ffi::TColgp_Array1OfPnt_ctor(1, points.len() as i32);
+                for (index, point) in points.iter().enumerate() {
+                    poles
+                        .pin_mut()
+                        .SetValue(index as i32 + 1, &point.to_3d(plane).to_occt_point());
+                }
+                let bezier = ffi::Geom_BezierCurve_to_handle(ffi::Geom_BezierCurve_ctor(&poles));
+                Some(ffi::TopoDS_Edge_to_owned(
+                    ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(
+                        &ffi::new_HandleGeomCurve_from_HandleGeom_BezierCurve(&bezier),
+                    )
+                    .pin_mut()
+                    .Edge(),
+                ))
+            }
+            Self::Circle(center, radius) => {
+                // OCCT starts a circle around the z-axis in positive x-direction, so building it
+                // in the xy-plane first and moving it onto the plane puts the seam at `start()`
+                let axis = ffi::gp_Ax2_ctor(
+                    &ffi::new_point(center.x().get::<meter>(), center.y().get::<meter>(), 0.),
+                    &ffi::gp_Dir_ctor(0., 0., 1.),
+                );
+                let circle = ffi::gp_Circ_ctor(&axis, radius.get::<meter>());
+                let mut make_edge = ffi::BRepBuilderAPI_MakeEdge_circle(&circle);
+                let mut operation = ffi::BRepBuilderAPI_Transform_ctor(
+                    ffi::cast_edge_to_shape(make_edge.pin_mut().Edge()),
+                    &Transform::to_plane(plane)
+                        .to_occt()
+                        .expect("planes have an orthonormal basis"),
+                    true,
+                );
+                Some(ffi::TopoDS_Edge_to_owned(ffi::TopoDS_cast_to_edge(
+                    operation.pin_mut().Shape(),
+                )))
+            }
             Self::Line(start, end) => {
                 let mut constructor = ffi::BRepBuilderAPI_MakeEdge_gp_Pnt_gp_Pnt(
                     &start.to_3d(plane).to_occt_point(),
@@ -174,6 +367,69 @@ impl Edge {
                 );
                 Some(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
             }
+            Self::Spline(points) => {
+                let mut occt_points = ffi::TColgp_HArray1OfPnt_ctor(1, points.len() as i32);
+                for (index, point) in points.iter().enumerate() {
+                    occt_points
+                        .pin_mut()
+                        .SetValue(index as i32 + 1, &point.to_3d(plane).to_occt_point());
+                }
+                let occt_points =
+                    ffi::new_HandleTColgpHArray1OfPnt_from_TColgpHArray1OfPnt(occt_points);
+
+                let mut interpolate = ffi::GeomAPI_Interpolate_ctor(&occt_points, false, 1e-9);
+                interpolate.pin_mut().Perform();
+                if !interpolate.IsDone() {
+                    return None;
+                }
+                let spline = ffi::GeomAPI_Interpolate_Curve(&interpolate);
+                Some(ffi::TopoDS_Edge_to_owned(
+                    ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(
+                        &ffi::new_HandleGeomCurve_from_HandleGeom_BSplineCurve(&spline),
+                    )
+                    .pin_mut()
+                    .Edge(),
+                ))
+            }
+        }
+    }
+
+    /// Evaluate the OCCT curve of this `Edge` at multiple normalized parameters.
+    fn occt_points_at(&self, parameters: &[f64]) -> Option<Vec<Point<2>>> {
+        let edge = self.to_occt(Plane::xy())?;
+        let curve = ffi::BRepAdaptor_Curve_ctor(&edge);
+        let (first, last) = (curve.FirstParameter(), curve.LastParameter());
+        Some(
+            parameters
+                .iter()
+                .map(|t| {
+                    let point = ffi::BRepAdaptor_Curve_value(&curve, first + (last - first) * t);
+                    Point::<2>::new([point.X().m(), point.Y().m()])
+                })
+                .collect(),
+        )
+    }
+
+    /// Return true if this `Edge` does not span any distance or can not be constructed.
+    pub(crate) fn is_degenerate(&self) -> bool {
+        match self {
+            Self::Arc(start, mid, end) => {
+                let start_mid = *mid - *start;
+                let start_end = *end - *start;
+                let cross = start_mid.x().get::<meter>() * start_end.y().get::<meter>()
+                    - start_mid.y().get::<meter>() * start_end.x().get::<meter>();
+                cross.abs() < f64::EPSILON
+            }
+            Self::Bezier(points) => {
+                points.len() < 2
+                    || points.len() > MAX_BEZIER_POINTS
+                    || points.iter().all(|point| *point == points[0])
+            }
+            Self::Circle(_, radius) => *radius <= Length::new::<meter>(0.),
+            Self::Line(start, end) => start == end,
+            Self::Spline(points) => {
+                points.len() < 2 || points.windows(2).any(|pair| pair[0] == pair[1])
+            }
         }
     }
 }
@@ -219,9 +475,302 @@ fn arc_center_radius(
     Ok((center, radius))
 }
 
+/// Return the angle of the start point and the signed angle swept by an arc.
+///
+/// Positive sweeps are counter clockwise.
+fn arc_start_angle_sweep(
+    center: Point<2>,
+    start: Point<2>,
+    interior: Point<2>,
+    end: Point<2>,
+) -> (Angle, Angle) {
+    let start_angle = arc_point_angle_on_unit_circle(center, start);
+    let start_to_interior =
+        (arc_point_angle_on_unit_circle(center, interior) - start_angle).normalized();
+    let start_to_end = (arc_point_angle_on_unit_circle(center, end) - start_angle).normalized();
+
+    if start_to_interior < start_to_end {
+        (start_angle, start_to_end)
+    } else {
+        (start_angle, start_to_end - Angle::FULL_TURN)
+    }
+}
+
+fn de_casteljau(points: &[Point<2>], t: f64) -> Point<2> {
+    let mut points = points.to_vec();
+    while points.len() > 1 {
+        points = points
+            .windows(2)
+            .map(|pair| pair[0].lerp(pair[1], t))
+            .collect();
+    }
+    points.first().copied().unwrap_or_else(Point::origin)
+}
+
 fn arc_point_angle_on_unit_circle(center: Point<2>, point: Point<2>) -> Angle {
     point
         .direction_from(center)
         .expect("center and point can not be the same")
         .angle()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Edge3D, IntoAngle, dir, point};
+    use approx::assert_relative_eq;
+
+    fn unit_circle_point(angle: Angle) -> Point<2> {
+        Dir::from(angle) * 1.m()
+    }
+
+    #[test]
+    fn split_line_in_half() {
+        let line = Edge::Line(point!(0, 0), point!(2.m(), 2.m()));
+        let (first, second) = line.split_at(0.5);
+        assert_eq!(first, Edge::Line(point!(0, 0), point!(1.m(), 1.m())));
+        assert_eq!(
+            second,
+            Edge::Line(point!(1.m(), 1.m()), point!(2.m(), 2.m()))
+        );
+        assert_relative_eq!(first.len().value, second.len().value);
+    }
+
+    #[test]
+    fn split_arc_preserves_sweep() {
+        let arc = Edge::Arc(
+            unit_circle_point(0.deg()),
+            unit_circle_point(-60.deg()),
+            unit_circle_point(-120.deg()),
+        );
+        let (first, second) = arc.split_at(0.25);
+        assert_eq!(first.start(), arc.start());
+        assert_eq!(first.end(), second.start());
+        assert_eq!(second.end(), arc.end());
+        assert_relative_eq!(first.end(), unit_circle_point(-30.deg()), epsilon = 1e-9);
+        assert_relative_eq!(
+            (first.len() + second.len()).value,
+            arc.len().value,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            first.tangent_at(1.).unwrap(),
+            second.tangent_at(0.).unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn split_circle_into_arcs() {
+        let circle = Edge::Circle(point!(1.m(), 1.m()), 1.m());
+        let (first, second) = circle.split_at(0.5);
+        assert_eq!(first.start(), circle.start());
+        assert_relative_eq!(first.end(), point!(0.m(), 1.m()), epsilon = 1e-9);
+        assert_eq!(second.end(), circle.start());
+        assert_relative_eq!(first.point_at(0.5), point!(1.m(), 2.m()), epsilon = 1e-9);
+        assert_relative_eq!(second.point_at(0.5), point!(1.m(), 0.m()), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn split_circle_and_arc_at_ends() {
+        let circle = Edge::Circle(point!(1.m(), 1.m()), 1.m());
+        let arc = Edge::Arc(point!(0, 0), point!(1.m(), 1.m()), point!(2.m(), 0.m()));
+        for edge in [circle, arc] {
+            let (start, end) = (edge.start(), edge.end());
+            assert_eq!(edge.split_at(0.), (Edge::Line(start, start), edge.clone()));
+            assert_eq!(edge.split_at(1.), (edge.clone(), Edge::Line(end, end)));
+        }
+    }
+
+    #[test]
+    fn split_bezier() {
+        let bezier = Edge::Bezier(vec![
+            point!(0, 0),
+            point!(1.m(), 2.m()),
+            point!(3.m(), 2.m()),
+            point!(4.m(), 0.m()),
+        ]);
+        let (first, second) = bezier.split_at(0.4);
+        assert_eq!(first.start(), bezier.start());
+        assert_eq!(first.end(), second.start());
+        assert_eq!(second.end(), bezier.end());
+        for t in [0., 0.3, 0.7, 1.] {
+            assert_relative_eq!(first.point_at(t), bezier.point_at(0.4 * t), epsilon = 1e-9);
+            assert_relative_eq!(
+                second.point_at(t),
+                bezier.point_at(0.4 + 0.6 * t),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn split_clamps_parameter() {
+        let line = Edge::Line(point!(0, 0), point!(2.m(), 0.m()));
+        assert_eq!(line.split_at(2.).0, line);
+    }
+
+    #[test]
+    fn len_arc_slightly_below_half_turn() {
+        let arc = Edge::Arc(
+            unit_circle_point(180.deg()),
+            unit_circle_point(90.deg()),
+            unit_circle_point(1.deg()),
+        );
+        assert_relative_eq!(arc.len().value, 179.deg().value, max_relative = 1e-9)
+    }
+
+    #[test]
+    fn len_arc_slightly_above_half_turn() {
+        let arc = Edge::Arc(
+            unit_circle_point(180.deg()),
+            unit_circle_point(90.deg()),
+            unit_circle_point((-1).deg()),
+        );
+        assert_relative_eq!(arc.len().value, 181.deg().value, max_relative = 1e-9)
+    }
+
+    #[test]
+    fn len_arc_almost_full_circle() {
+        let arc = Edge::Arc(
+            unit_circle_point(0.deg()),
+            unit_circle_point(180.deg()),
+            unit_circle_point(359.deg()),
+        );
+        assert_relative_eq!(arc.len().value, 359.deg().value, max_relative = 1e-9)
+    }
+
+    #[test]
+    fn circle_start_end() {
+        let circle = Edge::Circle(point!(1.m(), 2.m()), 3.m());
+        assert_eq!(circle.start(), point!(4.m(), 2.m()));
+        assert_eq!(circle.end(), circle.start());
+    }
+
+    #[test]
+    fn circle_occt_seam_matches_start() {
+        let circle = Edge::Circle(point!(1.m(), 2.m()), 3.m());
+        for plane in [Plane::xy(), Plane::xz(), Plane::yz()] {
+            let edge = Edge3D::from_occt(&circle.to_occt(plane).unwrap());
+            assert_relative_eq!(edge.start(), circle.start().to_3d(plane), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn circle_len() {
+        let circle = Edge::Circle(point!(1.m(), 2.m()), 2.m());
+        assert_relative_eq!(circle.len().value, 4. * f64::consts::PI);
+    }
+
+    #[test]
+    fn circle_point_and_tangent_at() {
+        let circle = Edge::Circle(point!(0, 0), 2.m());
+        assert_relative_eq!(circle.point_at(0.25), point!(0.m(), 2.m()), epsilon = 1e-9);
+        assert_relative_eq!(
+            circle.tangent_at(0.25).unwrap(),
+            dir!(-1, 0),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(circle.end_direction().unwrap(), dir!(0, 1), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn circle_is_degenerate() {
+        assert!(Edge::Circle(point!(0, 0), 0.m()).is_degenerate());
+        assert!(!Edge::Circle(point!(0, 0), 1.m()).is_degenerate());
+    }
+
+    #[test]
+    fn point_at_semicircle_middle() {
+        let arc = Edge::Arc(
+            point!(-1.m(), 0.m()),
+            point!(0.m(), 1.m()),
+            point!(1.m(), 0.m()),
+        );
+        assert_relative_eq!(arc.point_at(0.5), point!(0.m(), 1.m()), epsilon = 1e-9);
+        assert_relative_eq!(arc.point_at(0.), point!(-1.m(), 0.m()), epsilon = 1e-9);
+        assert_relative_eq!(arc.point_at(1.), point!(1.m(), 0.m()), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn point_at_clockwise_arc() {
+        let arc = Edge::Arc(
+            point!(1.m(), 0.m()),
+            point!(0.m(), -1.m()),
+            point!(-1.m(), 0.m()),
+        );
+        assert_relative_eq!(arc.point_at(0.5), point!(0.m(), -1.m()), epsilon = 1e-9);
+        assert_relative_eq!(arc.tangent_at(0.5).unwrap(), dir!(-1, 0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn point_at_large_arc() {
+        let arc = Edge::Arc(
+            unit_circle_point(0.deg()),
+            unit_circle_point(180.deg()),
+            unit_circle_point(270.deg()),
+        );
+        assert_relative_eq!(
+            arc.point_at(2. / 3.),
+            unit_circle_point(180.deg()),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(arc.tangent_at(1.).unwrap(), dir!(1, 0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tangent_at_end_matches_end_direction() {
+        let arc = Edge::Arc(
+            point!(-1.m(), 0.m()),
+            point!(0.m(), 1.m()),
+            point!(1.m(), 0.m()),
+        );
+        assert_relative_eq!(
+            arc.tangent_at(1.).unwrap(),
+            arc.end_direction().unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn point_at_bezier() {
+        let bezier = Edge::Bezier(vec![
+            point!(0, 0),
+            point!(0.m(), 1.m()),
+            point!(1.m(), 1.m()),
+            point!(1.m(), 0.m()),
+        ]);
+        assert_relative_eq!(bezier.point_at(0.5), point!(0.5.m(), 0.75.m()));
+        assert_relative_eq!(bezier.tangent_at(0.).unwrap(), dir!(0, 1));
+        assert_relative_eq!(bezier.tangent_at(0.5).unwrap(), dir!(1, 0));
+        assert_relative_eq!(bezier.tangent_at(1.).unwrap(), dir!(0, -1));
+    }
+
+    #[test]
+    fn len_arc_collinear_points() {
+        let arc = Edge::Arc(point!(0, 0), point!(1.m(), 0.m()), point!(2.m(), 0.m()));
+        assert_eq!(arc.len(), 0.m())
+    }
+
+    #[test]
+    fn empty_bezier_and_spline() {
+        for edge in [Edge::Bezier(vec![]), Edge::Spline(vec![])] {
+            assert_eq!(edge.start(), Point::origin());
+            assert_eq!(edge.end(), Point::origin());
+            assert_eq!(edge.point_at(0.5), Point::origin());
+            assert_eq!(edge.split_at(0.5), (edge.clone(), edge.clone()));
+            assert_eq!(edge.end_direction(), Err(Error::ZeroVector));
+            assert!(edge.is_degenerate());
+        }
+    }
+
+    #[test]
+    fn bezier_exceeding_max_degree() {
+        let points: Vec<Point<2>> = (0..=MAX_BEZIER_POINTS)
+            .map(|i| point!((i as f64).m(), ((i % 2) as f64).m()))
+            .collect();
+        let bezier = Edge::Bezier(points);
+        assert!(bezier.is_degenerate());
+        assert!(bezier.to_occt(Plane::xy()).is_none());
+    }
+}
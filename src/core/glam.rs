@@ -0,0 +1,64 @@
+use glam::Vec3;
+use uom::si::length::meter;
+
+use crate::{Dir, Length, Point};
+
+impl From<Point<3>> for Vec3 {
+    /// Convert a `Point<3>` into a glam `Vec3` with coordinates in meters.
+    fn from(value: Point<3>) -> Self {
+        Vec3::new(
+            value.x().get::<meter>() as f32,
+            value.y().get::<meter>() as f32,
+            value.z().get::<meter>() as f32,
+        )
+    }
+}
+
+impl From<Vec3> for Point<3> {
+    /// Convert a glam `Vec3` with coordinates in meters into a `Point<3>`.
+    fn from(value: Vec3) -> Self {
+        Point::<3>::new([
+            Length::new::<meter>(value.x as f64),
+            Length::new::<meter>(value.y as f64),
+            Length::new::<meter>(value.z as f64),
+        ])
+    }
+}
+
+impl From<Dir<3>> for Vec3 {
+    /// Convert a `Dir<3>` into a glam `Vec3` of length 1.
+    fn from(value: Dir<3>) -> Self {
+        Vec3::new(value.x() as f32, value.y() as f32, value.z() as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoLength, dir, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_round_trip() {
+        let point = point!(1.5.m(), (-2).mm(), 3.25.ft());
+        assert_relative_eq!(
+            Point::<3>::from(Vec3::from(point)),
+            point,
+            max_relative = f32::EPSILON as f64
+        );
+    }
+
+    #[test]
+    fn point_in_meters() {
+        assert_eq!(
+            Vec3::from(point!(1.m(), 2.cm(), 3.mm())),
+            Vec3::new(1., 0.02, 0.003)
+        );
+    }
+
+    #[test]
+    fn dir() {
+        assert_eq!(Vec3::from(dir!(0, 0, 1)), Vec3::Z);
+        assert_relative_eq!(Vec3::from(dir!(1, 1, 1)).length(), 1.);
+    }
+}
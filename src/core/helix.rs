@@ -0,0 +1,249 @@
+use std::f64::consts::TAU;
+
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{Edge3D, Length, Plane, Point, Transform};
+
+/// A helical curve winding around the normal axis of a `Plane`.
+///
+/// The helix starts on the x-axis of the `Plane`, winds counterclockwise around its normal and
+/// advances by one pitch along the normal with every full turn. Helices form the basis for
+/// threads and springs.
+///
+/// ```rust
+/// use anvil::{Helix, IntoLength, Plane, point};
+///
+/// let helix = Helix::new(5.mm(), 10.mm(), 2., Plane::xy());
+/// assert_eq!(helix.start(), point!(5.mm(), 0.mm(), 0.mm()));
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Helix {
+    radius: Length,
+    pitch: Length,
+    turns: f64,
+    plane: Plane,
+}
+impl Helix {
+    /// Construct a `Helix` from its radius, the axial distance per turn and the number of turns.
+    ///
+    /// A negative number of turns results in a clockwise helix.
+    pub fn new(radius: Length, pitch: Length, turns: f64, plane: Plane) -> Self {
+        Self {
+            radius,
+            pitch,
+            turns,
+            plane,
+        }
+    }
+
+    /// Return the radius of this `Helix`.
+    pub fn radius(&self) -> Length {
+        self.radius
+    }
+
+    /// Return the distance this `Helix` advances along its axis per turn.
+    pub fn pitch(&self) -> Length {
+        self.pitch
+    }
+
+    /// Return the number of turns of this `Helix`.
+    pub fn turns(&self) -> f64 {
+        self.turns
+    }
+
+    /// Return the `Plane` this `Helix` starts on.
+    pub fn plane(&self) -> Plane {
+        self.plane
+    }
+
+    /// Return the point on this `Helix` after a given number of turns.
+    ///
+    /// ```rust
+    /// use anvil::{Helix, IntoLength, Plane, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let helix = Helix::new(1.m(), 4.m(), 1., Plane::xy());
+    /// assert_relative_eq!(helix.point_at(0.25), point!(0.m(), 1.m(), 1.m()));
+    /// ```
+    pub fn point_at(&self, turn: f64) -> Point<3> {
+        let angle = TAU * turn;
+        let offset = Point::<2>::new([self.radius * angle.cos(), self.radius * angle.sin()]);
+        offset.to_3d(self.plane) + self.plane.normal() * (self.pitch * turn.abs())
+    }
+
+    /// Return the starting point of this `Helix`.
+    pub fn start(&self) -> Point<3> {
+        self.point_at(0.)
+    }
+
+    /// Return the end point of this `Helix`.
+    ///
+    /// ```rust
+    /// use anvil::{Helix, IntoLength, Plane, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let helix = Helix::new(5.mm(), 10.mm(), 1., Plane::xy());
+    /// assert_relative_eq!(helix.end(), point!(5.mm(), 0.mm(), 10.mm()));
+    /// ```
+    pub fn end(&self) -> Point<3> {
+        self.point_at(self.turns)
+    }
+
+    /// Return the arc length of this `Helix`.
+    ///
+    /// ```rust
+    /// use anvil::{Helix, IntoLength, Plane};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let helix = Helix::new(1.m(), 0.m(), 1., Plane::xy());
+    /// assert_relative_eq!(helix.length().value, std::f64::consts::TAU);
+    /// ```
+    pub fn length(&self) -> Length {
+        let circumference = self.radius * TAU;
+        let per_turn = (circumference * circumference + self.pitch * self.pitch).sqrt();
+        per_turn * self.turns.abs()
+    }
+
+    /// Return evenly spaced points along this `Helix`, including its start and end point.
+    ///
+    /// ```rust
+    /// use anvil::{Helix, IntoLength, Plane};
+    ///
+    /// let helix = Helix::new(1.m(), 1.m(), 2., Plane::xy());
+    /// assert_eq!(helix.points(8).len(), 17);
+    /// ```
+    pub fn points(&self, points_per_turn: usize) -> Vec<Point<3>> {
+        let segments = (self.turns.abs() * points_per_turn as f64).ceil().max(1.) as usize;
+        (0..=segments)
+            .map(|i| self.point_at(self.turns * i as f64 / segments as f64))
+            .collect()
+    }
+
+    /// Return the exact curve of this `Helix` as an `Edge3D`, or `None` if it does not make any
+    /// turn.
+    ///
+    /// The curve is a straight line in the parameter space of a cylindrical surface around the
+    /// normal of the `Plane`.
+    ///
+    /// ```rust
+    /// use anvil::{Helix, IntoLength, Plane};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let helix = Helix::new(5.mm(), 10.mm(), 2., Plane::xy());
+    /// let edge = helix.to_edge().unwrap();
+    /// assert_relative_eq!(edge.len().value, helix.length().value, max_relative = 1e-6);
+    /// ```
+    pub fn to_edge(self) -> Option<Edge3D> {
+        let radius = self.radius.get::<meter>();
+        if radius <= 0. || self.turns == 0. {
+            return None;
+        }
+
+        let axis = ffi::gp_Ax2_ctor(&ffi::new_point(0., 0., 0.), &ffi::gp_Dir_ctor(0., 0., 1.));
+        let cylinder = ffi::Geom_CylindricalSurface_ctor(&ffi::gp_Ax3_from_gp_Ax2(&axis), radius);
+        let surface = ffi::cylinder_to_surface(&cylinder);
+
+        // the parameters of the surface are the angle around and the height along the z-axis
+        let (angle, height) = (
+            TAU * self.turns,
+            self.pitch.get::<meter>() * self.turns.abs(),
+        );
+        let segment = ffi::GCE2d_MakeSegment_point_point(
+            &ffi::new_point_2d(0., 0.),
+            &ffi::new_point_2d(angle, height),
+        );
+        let mut make_edge = ffi::BRepBuilderAPI_MakeEdge_CurveSurface2d(
+            &ffi::new_HandleGeomCurve2d_from_HandleGeom2d_TrimmedCurve(&segment),
+            &surface,
+            0.,
+            angle.hypot(height),
+        );
+        let edge = ffi::cast_edge_to_shape(make_edge.pin_mut().Edge());
+        ffi::BRepLibBuildCurves3d(edge);
+
+        let mut operation = ffi::BRepBuilderAPI_Transform_ctor(
+            edge,
+            &Transform::to_plane(self.plane)
+                .to_occt()
+                .expect("planes have an orthonormal basis"),
+            true,
+        );
+        Some(Edge3D::from_occt(ffi::TopoDS_cast_to_edge(
+            operation.pin_mut().Shape(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoLength, dir, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn one_turn_advances_by_pitch() {
+        let helix = Helix::new(3.mm(), 10.mm(), 1., Plane::xy());
+        assert_relative_eq!(helix.end(), point!(3.mm(), 0.mm(), 10.mm()));
+        assert_relative_eq!(
+            helix.end().distance_to(helix.start()).value,
+            0.01,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn half_turn_on_other_plane() {
+        let plane = Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(0, 1, 0), dir!(0, 0, 1)).unwrap();
+        let helix = Helix::new(2.m(), 4.m(), 0.5, plane);
+        assert_relative_eq!(helix.end(), point!(2.m(), -2.m(), 1.m()), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn clockwise_helix_advances_along_normal() {
+        let helix = Helix::new(1.m(), 2.m(), -0.25, Plane::xy());
+        assert_relative_eq!(helix.end(), point!(0.m(), -1.m(), 0.5.m()), epsilon = 1e-12);
+    }
+
+    fn occt_end(helix: &Helix) -> Point<3> {
+        helix.to_edge().unwrap().end()
+    }
+
+    #[test]
+    fn occt_edge_advances_by_pitch() {
+        let helix = Helix::new(3.mm(), 10.mm(), 1., Plane::xy());
+        assert_relative_eq!(
+            occt_end(&helix),
+            point!(3.mm(), 0.mm(), 10.mm()),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn occt_edge_on_other_plane() {
+        let plane = Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(0, 1, 0), dir!(0, 0, 1)).unwrap();
+        let helix = Helix::new(2.m(), 4.m(), 0.5, plane);
+        assert_relative_eq!(occt_end(&helix), helix.end(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn occt_edge_without_turns() {
+        assert!(
+            Helix::new(1.m(), 1.m(), 0., Plane::xy())
+                .to_edge()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn points_lie_on_cylinder() {
+        let helix = Helix::new(2.m(), 1.m(), 3., Plane::xy());
+        for point in helix.points(7) {
+            assert_relative_eq!(
+                (point.x() * point.x() + point.y() * point.y()).sqrt().value,
+                2.,
+                epsilon = 1e-12
+            );
+        }
+    }
+}
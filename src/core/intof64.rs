@@ -3,6 +3,14 @@ pub trait IntoF64 {
     /// Convert this number into an f64
     fn to_f64(&self) -> f64;
 }
+/// Return the bit pattern of an f64 that can be used as a hash key.
+///
+/// Positive and negative zero are mapped to the same key so that values comparing equal share a
+/// key. NaN values are never equal to each other but may share a key.
+pub(crate) fn hash_key(value: f64) -> u64 {
+    if value == 0. { 0 } else { value.to_bits() }
+}
+
 macro_rules! impl_into_f64 {
     ($t:tt) => {
         impl IntoF64 for $t {
@@ -27,3 +35,19 @@ impl_into_f64!(i64);
 impl_into_f64!(i128);
 impl_into_f64!(f32);
 impl_into_f64!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_key_zero() {
+        assert_eq!(hash_key(0.), hash_key(-0.));
+    }
+
+    #[test]
+    fn hash_key_distinct() {
+        assert_ne!(hash_key(1.), hash_key(-1.));
+        assert_ne!(hash_key(0.1 + 0.2), hash_key(0.3));
+    }
+}
@@ -1,7 +1,10 @@
-use std::ops::Mul;
+use std::{cmp::Ordering, ops::Mul};
 
-use uom::si::length::{centimeter, decimeter, foot, inch, meter, millimeter, yard};
+use uom::si::length::{
+    centimeter, decimeter, foot, inch, meter, micrometer, mil, millimeter, nautical_mile, yard,
+};
 
+use super::intof64::hash_key;
 use crate::{Dir, IntoF64, Point};
 
 /// A physical length (i.e. a distance).
@@ -34,6 +37,77 @@ impl<const DIM: usize> Mul<Dir<DIM>> for Length {
     }
 }
 
+/// Import this trait to use additional methods on `Length`s.
+///
+/// Negation as well as `abs()`, `min()` and `max()` are already provided by `Length` itself and
+/// need neither this trait nor any uom imports.
+///
+/// ```rust
+/// use anvil::IntoLength;
+///
+/// assert_eq!(-(2.m()), (-2).m());
+/// assert_eq!((-5).m().abs(), 5.m());
+/// assert_eq!(1.m().min(2.m()), 1.m());
+/// assert_eq!(1.m().max(2.m()), 2.m());
+/// ```
+pub trait LengthExt {
+    /// Restrict this `Length` to the range `[min, max]`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, LengthExt};
+    ///
+    /// assert_eq!(5.m().clamp(0.m(), 2.m()), 2.m());
+    /// assert_eq!((-1).m().clamp(0.m(), 2.m()), 0.m());
+    /// assert_eq!(1.m().clamp(0.m(), 2.m()), 1.m());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    fn clamp(&self, min: Length, max: Length) -> Length;
+
+    /// Return the total ordering between this `Length` and another.
+    ///
+    /// This can be used to sort `Length`s, which are only `PartialOrd`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, LengthExt};
+    ///
+    /// let mut lengths = vec![3.m(), 1.m(), 2.m()];
+    /// lengths.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(lengths, vec![1.m(), 2.m(), 3.m()]);
+    /// ```
+    fn total_cmp(&self, other: &Length) -> Ordering;
+
+    /// Return a key based on the exact bit pattern of this `Length` in meters.
+    ///
+    /// `Length`s can not implement `Hash` themselves. The key can be used instead, for example in a
+    /// `HashMap`. Equal `Length`s have equal keys.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, LengthExt};
+    ///
+    /// assert_eq!(1.m().hash_key(), 100.cm().hash_key());
+    /// assert_ne!(1.m().hash_key(), 1.mm().hash_key());
+    /// ```
+    fn hash_key(&self) -> u64;
+}
+impl LengthExt for Length {
+    fn clamp(&self, min: Length, max: Length) -> Length {
+        Length::new::<meter>(
+            self.get::<meter>()
+                .clamp(min.get::<meter>(), max.get::<meter>()),
+        )
+    }
+
+    fn total_cmp(&self, other: &Length) -> Ordering {
+        self.get::<meter>().total_cmp(&other.get::<meter>())
+    }
+
+    fn hash_key(&self) -> u64 {
+        hash_key(self.get::<meter>())
+    }
+}
+
 /// Return true if any IntoLength in the input array is zero.
 pub fn is_zero(lengths: &[Length]) -> bool {
     for length in lengths {
@@ -131,6 +205,39 @@ pub trait IntoLength: IntoF64 {
     fn mm(&self) -> Length {
         Length::new::<millimeter>(self.to_f64())
     }
+    /// Convert this number into a `Length` in micrometers.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Length};
+    /// use uom::si::length::micrometer;
+    ///
+    /// assert_eq!(5.um(), Length::new::<micrometer>(5.));
+    /// ```
+    fn um(&self) -> Length {
+        Length::new::<micrometer>(self.to_f64())
+    }
+    /// Convert this number into a `Length` in mils (thousandths of an inch).
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Length};
+    /// use uom::si::length::mil;
+    ///
+    /// assert_eq!(5.mil(), Length::new::<mil>(5.));
+    /// ```
+    fn mil(&self) -> Length {
+        Length::new::<mil>(self.to_f64())
+    }
+    /// Convert this number into a `Length` in nautical miles.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Length};
+    /// use uom::si::length::nautical_mile;
+    ///
+    /// assert_eq!(5.nmi(), Length::new::<nautical_mile>(5.));
+    /// ```
+    fn nmi(&self) -> Length {
+        Length::new::<nautical_mile>(self.to_f64())
+    }
 }
 
 impl IntoLength for usize {}
@@ -151,6 +258,7 @@ impl IntoLength for f64 {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn add() {
@@ -168,6 +276,68 @@ mod tests {
         assert_eq!(4. * 5.m(), 20.m());
     }
 
+    #[test]
+    fn sum() {
+        let lengths = [1.m(), 2.m(), 3.m()];
+        assert_eq!(lengths.iter().copied().sum::<Length>(), 6.m());
+        assert_eq!(lengths.into_iter().sum::<Length>(), 6.m());
+    }
+
+    #[test]
+    fn sum_empty() {
+        assert_eq!(Vec::<Length>::new().into_iter().sum::<Length>(), 0.m());
+    }
+
+    #[test]
+    fn small_units() {
+        assert_relative_eq!(1000.um().value, 1.mm().value);
+        assert_relative_eq!(1.in_().value, 1000.mil().value);
+    }
+
+    #[test]
+    fn nautical_units() {
+        assert_relative_eq!(1.nmi().value, 1852.m().value);
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(5.m().clamp(0.m(), 2.m()), 2.m());
+        assert_eq!((-5).m().clamp(0.m(), 2.m()), 0.m());
+        assert_eq!(1.5.m().clamp(0.m(), 2.m()), 1.5.m());
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-(5.m()), (-5).m());
+        assert_eq!(-(-(3.cm())), 3.cm());
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!((-5).m().abs(), 5.m());
+        assert_eq!(5.m().abs(), 5.m());
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(1.m().min(2.m()), 1.m());
+        assert_eq!((-1).m().min(2.m()), (-1).m());
+        assert_eq!(1.m().max(2.m()), 2.m());
+        assert_eq!(10.cm().max(1.mm()), 10.cm());
+    }
+
+    #[test]
+    fn sort_with_total_cmp() {
+        let mut lengths = vec![2.m(), (-1).m(), 10.cm(), 1.ft()];
+        lengths.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!(lengths, vec![(-1).m(), 10.cm(), 1.ft(), 2.m()]);
+    }
+
+    #[test]
+    fn hash_key_of_zero() {
+        assert_eq!(0.m().hash_key(), (-0.).m().hash_key());
+    }
+
     #[test]
     fn divide_with_f64() {
         assert_eq!(6.m() / 2., 3.m());
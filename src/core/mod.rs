@@ -2,18 +2,26 @@ mod angle;
 mod axis;
 mod dir;
 mod edge;
+#[cfg(feature = "glam")]
+mod glam;
+mod helix;
 mod intof64;
 mod length;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
 mod path;
 mod plane;
 mod point;
+mod transform;
 
-pub use angle::{Angle, IntoAngle};
+pub use angle::{Angle, AngleExt, IntoAngle};
 pub use axis::Axis;
 pub use dir::Dir;
 pub use edge::Edge;
+pub use helix::Helix;
 pub use intof64::IntoF64;
-pub use length::{IntoLength, Length, is_zero};
+pub use length::{IntoLength, Length, LengthExt, is_zero};
 pub use path::Path;
 pub use plane::Plane;
 pub use point::Point;
+pub use transform::Transform;
@@ -0,0 +1,46 @@
+use nalgebra::Point3;
+use uom::si::length::meter;
+
+use crate::{Length, Point};
+
+impl From<Point<3>> for Point3<f64> {
+    /// Convert a `Point<3>` into a nalgebra `Point3` with coordinates in meters.
+    fn from(value: Point<3>) -> Self {
+        Point3::new(
+            value.x().get::<meter>(),
+            value.y().get::<meter>(),
+            value.z().get::<meter>(),
+        )
+    }
+}
+
+impl From<Point3<f64>> for Point<3> {
+    /// Convert a nalgebra `Point3` with coordinates in meters into a `Point<3>`.
+    fn from(value: Point3<f64>) -> Self {
+        Point::<3>::new([
+            Length::new::<meter>(value.x),
+            Length::new::<meter>(value.y),
+            Length::new::<meter>(value.z),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoLength, point};
+
+    #[test]
+    fn point_round_trip() {
+        let point = point!(1.5.m(), (-2).mm(), 3.25.ft());
+        assert_eq!(Point::<3>::from(Point3::from(point)), point);
+    }
+
+    #[test]
+    fn point_in_meters() {
+        assert_eq!(
+            Point3::from(point!(1.m(), 2.cm(), 3.mm())),
+            Point3::new(1., 0.02, 0.003)
+        );
+    }
+}
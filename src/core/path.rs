@@ -1,7 +1,11 @@
+use core::f64;
+
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
 use uom::si::angle::{degree, radian};
 use uom::si::length::meter;
 
-use crate::{Angle, Axis, Dir, Edge, Length, Point, Sketch};
+use crate::{Angle, Axis, Dir, Edge, Error, Length, Part, Plane, Point, Sketch};
 
 /// A continuous series of edges (i.e. lines, arcs, ...).
 #[derive(Debug, PartialEq, Clone)]
@@ -34,7 +38,16 @@ impl Path {
     /// assert_eq!(path.end(), point!(3.m(), 4.m()))
     /// ```
     pub fn line_to(&self, point: Point<2>) -> Self {
-        self.add_edge(Edge::Line(self.cursor, point))
+        self.try_line_to(point).expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::line_to`.
+    ///
+    /// Like the other `try_` builders, this adds an edge that starts at the cursor and therefore
+    /// always connects to the end of this `Path`. Use `Path::try_add_edge` to append arbitrary
+    /// edges that might not.
+    pub fn try_line_to(&self, point: Point<2>) -> Result<Self, Error> {
+        self.try_add_edge(Edge::Line(self.cursor, point))
     }
 
     /// Add a line to the end of this `Path` that extends by a specified amount in x and y direction.
@@ -46,7 +59,12 @@ impl Path {
     /// assert_eq!(path.end(), point!(4.m(), 6.m()))
     /// ```
     pub fn line_by(&self, dx: Length, dy: Length) -> Self {
-        self.add_edge(Edge::Line(
+        self.try_line_by(dx, dy).expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::line_by`.
+    pub fn try_line_by(&self, dx: Length, dy: Length) -> Result<Self, Error> {
+        self.try_add_edge(Edge::Line(
             self.cursor,
             self.cursor + Point::<2>::new([dx, dy]),
         ))
@@ -78,8 +96,14 @@ impl Path {
     /// )
     /// ```
     pub fn arc_by(&self, radius: Length, angle: Angle) -> Self {
+        self.try_arc_by(radius, angle)
+            .expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::arc_by`.
+    pub fn try_arc_by(&self, radius: Length, angle: Angle) -> Result<Self, Error> {
         if radius == Length::new::<meter>(0.) || angle == Angle::new::<radian>(0.) {
-            return self.clone();
+            return Ok(self.clone());
         }
         let center = self.cursor + self.end_direction().rotate(Angle::new::<degree>(90.)) * radius;
         let center_cursor_axis =
@@ -97,7 +121,7 @@ impl Path {
                 .rotate(angle * direction_factor)
                 * radius.abs();
 
-        self.add_edge(Edge::Arc(self.cursor, interim_point, end_point))
+        self.try_add_edge(Edge::Arc(self.cursor, interim_point, end_point))
     }
 
     /// Add a circle section to the end of this `Path` two points.
@@ -110,7 +134,216 @@ impl Path {
     /// assert_eq!(path.edges(), vec![Edge::Arc(point!(0, 0), point!(1.m(), 1.m()), point!(0.m(), 2.m()))]);
     /// ```
     pub fn arc_points(&self, mid: Point<2>, end: Point<2>) -> Self {
-        self.add_edge(Edge::Arc(self.cursor, mid, end))
+        self.try_arc_points(mid, end)
+            .expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::arc_points`.
+    pub fn try_arc_points(&self, mid: Point<2>, end: Point<2>) -> Result<Self, Error> {
+        self.try_add_edge(Edge::Arc(self.cursor, mid, end))
+    }
+
+    /// Add a cubic Bézier curve to the end of this `Path`.
+    ///
+    /// The curve starts at the cursor, is pulled towards the two control points and ends at the
+    /// specified end point.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, IntoLength, Path, point};
+    ///
+    /// let path = Path::at(point!(0, 0)).bezier_to(
+    ///     point!(1.m(), 0.m()),
+    ///     point!(1.m(), 1.m()),
+    ///     point!(2.m(), 1.m()),
+    /// );
+    /// assert_eq!(path.end(), point!(2.m(), 1.m()));
+    /// assert_eq!(
+    ///     path.edges(),
+    ///     vec![Edge::Bezier(vec![
+    ///         point!(0, 0),
+    ///         point!(1.m(), 0.m()),
+    ///         point!(1.m(), 1.m()),
+    ///         point!(2.m(), 1.m()),
+    ///     ])]
+    /// );
+    /// ```
+    pub fn bezier_to(&self, control1: Point<2>, control2: Point<2>, end: Point<2>) -> Self {
+        self.try_bezier_to(control1, control2, end)
+            .expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::bezier_to`.
+    pub fn try_bezier_to(
+        &self,
+        control1: Point<2>,
+        control2: Point<2>,
+        end: Point<2>,
+    ) -> Result<Self, Error> {
+        self.try_add_edge(Edge::Bezier(vec![self.cursor, control1, control2, end]))
+    }
+
+    /// Add a smooth spline to the end of this `Path` that passes through all given points.
+    ///
+    /// The spline starts at the cursor and ends at the last point. Points that are identical to
+    /// their predecessor are skipped. If no new points are given, the `Path` is returned unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, IntoLength, Path, point};
+    ///
+    /// let path = Path::at(point!(0, 0))
+    ///     .spline_through(&[point!(1.m(), 1.m()), point!(2.m(), 0.m()), point!(3.m(), 1.m())]);
+    /// assert_eq!(path.end(), point!(3.m(), 1.m()));
+    /// assert_eq!(
+    ///     path.edges(),
+    ///     vec![Edge::Spline(vec![
+    ///         point!(0, 0),
+    ///         point!(1.m(), 1.m()),
+    ///         point!(2.m(), 0.m()),
+    ///         point!(3.m(), 1.m()),
+    ///     ])]
+    /// );
+    /// ```
+    pub fn spline_through(&self, points: &[Point<2>]) -> Self {
+        self.try_spline_through(points)
+            .expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::spline_through`.
+    pub fn try_spline_through(&self, points: &[Point<2>]) -> Result<Self, Error> {
+        let mut spline_points = vec![self.cursor];
+        for point in points {
+            if spline_points.last() != Some(point) {
+                spline_points.push(*point);
+            }
+        }
+        if spline_points.len() < 2 {
+            return Ok(self.clone());
+        }
+        self.try_add_edge(Edge::Spline(spline_points))
+    }
+
+    /// Add a circle section to the end of this `Path` that continues tangentially and ends at a
+    /// specified point.
+    ///
+    /// The arc leaves the `Path` in its current `end_direction()`. If the end point lies on a
+    /// straight line in front of the cursor, a line is added instead. If it lies behind the cursor
+    /// on that line or on the cursor itself, no tangent arc exists and the `Path` is returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Path, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let path = Path::at(point!(0, 0))
+    ///     .line_to(point!(1.m(), 0.m()))
+    ///     .tangent_arc_to(point!(2.m(), 1.m()));
+    /// assert_eq!(path.end(), point!(2.m(), 1.m()));
+    /// assert_relative_eq!(path.end_direction(), dir!(0, 1));
+    /// ```
+    pub fn tangent_arc_to(&self, end: Point<2>) -> Self {
+        self.try_tangent_arc_to(end)
+            .expect("path is not continuous")
+    }
+
+    /// Fallible version of `Path::tangent_arc_to`.
+    pub fn try_tangent_arc_to(&self, end: Point<2>) -> Result<Self, Error> {
+        let direction = self.end_direction();
+        let normal = direction.rotate(Angle::new::<degree>(90.));
+        let chord = end - self.cursor;
+        let (chord_x, chord_y) = (chord.x().get::<meter>(), chord.y().get::<meter>());
+        let chord_len = f64::sqrt(chord_x.powi(2) + chord_y.powi(2));
+
+        let along = direction.x() * chord_x + direction.y() * chord_y;
+        let across = normal.x() * chord_x + normal.y() * chord_y;
+
+        let end_is_on_tangent = across.abs() <= chord_len * 1e-9;
+        if end_is_on_tangent {
+            return if along > 0. {
+                self.try_line_to(end)
+            } else {
+                Ok(self.clone())
+            };
+        }
+
+        let radius = Length::new::<meter>(chord_len.powi(2) / (2. * across));
+        let center = self.cursor + normal * radius;
+        let sweep = Angle::new::<radian>(2. * across.abs().atan2(along));
+        let direction_factor: f64 = (radius / radius.abs()).into();
+
+        let center_cursor_axis =
+            Axis::<2>::between(center, self.cursor).expect("radius is not zero");
+        let mid = center
+            + center_cursor_axis
+                .direction
+                .rotate(sweep / 2. * direction_factor)
+                * radius.abs();
+
+        self.try_add_edge(Edge::Arc(self.cursor, mid, end))
+    }
+
+    /// Round every corner between two lines of this `Path` with a tangent arc of a given radius.
+    ///
+    /// If the `Path` already ends at its start, the corner between the last and the first line is
+    /// rounded as well. Corners are left sharp if the arc would need more than half of the length
+    /// of one of the adjacent lines, as are corners without a change of direction or with a full
+    /// reversal. Corners involving other edges than lines are not modified. A radius of zero or
+    /// less leaves the `Path` unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Path, point};
+    ///
+    /// let path = Path::at(point!(0, 0))
+    ///     .line_to(point!(2.m(), 0.m()))
+    ///     .line_to(point!(2.m(), 2.m()))
+    ///     .smooth(1.m());
+    /// assert_eq!(path.edges().len(), 3);
+    /// assert_eq!(path.start(), point!(0, 0));
+    /// assert_eq!(path.end(), point!(2.m(), 2.m()));
+    /// ```
+    pub fn smooth(&self, radius: Length) -> Self {
+        if radius <= Length::new::<meter>(0.) {
+            return self.clone();
+        }
+        let edge_count = self.edges.len();
+        let is_closed = edge_count > 1 && self.start() == self.end();
+
+        let fillets: Vec<Option<[Point<2>; 3]>> = (0..edge_count)
+            .map(|i| {
+                if i + 1 == edge_count && !is_closed {
+                    return None;
+                }
+                match (&self.edges[i], &self.edges[(i + 1) % edge_count]) {
+                    (Edge::Line(start, corner), Edge::Line(_, end)) => {
+                        corner_fillet(*start, *corner, *end, radius)
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let mut edges = vec![];
+        for (i, edge) in self.edges.iter().enumerate() {
+            let previous_fillet = match i {
+                0 if is_closed => fillets[edge_count - 1],
+                0 => None,
+                _ => fillets[i - 1],
+            };
+            match edge {
+                Edge::Line(start, end) => edges.push(Edge::Line(
+                    previous_fillet.map_or(*start, |[_, _, fillet_end]| fillet_end),
+                    fillets[i].map_or(*end, |[fillet_start, _, _]| fillet_start),
+                )),
+                _ => edges.push(edge.clone()),
+            }
+            if let Some([fillet_start, mid, fillet_end]) = fillets[i] {
+                edges.push(Edge::Arc(fillet_start, mid, fillet_end));
+            }
+        }
+
+        Self {
+            cursor: edges.last().map_or(self.cursor, Edge::end),
+            edges,
+        }
     }
 
     /// Connect the end of this `Path` to its start with a straight line and return the resulting `Sketch`.
@@ -164,7 +397,9 @@ impl Path {
 
     /// Return the direction the last element of this `Path` is pointing to.
     ///
-    /// If the path is empty, a `Dir` parallel to the positive x-direction is returned.
+    /// Degenerate edges without a direction, like zero-length lines, are skipped. If no edge has a
+    /// direction, e.g. because the path is empty, a `Dir` parallel to the positive x-direction is
+    /// returned.
     ///
     /// ```rust
     /// use anvil::{IntoLength, Path, dir, point};
@@ -179,12 +414,40 @@ impl Path {
     /// )
     /// ```
     pub fn end_direction(&self) -> Dir<2> {
-        match self.edges.last() {
-            Some(last_edge) => last_edge
-                .end_direction()
-                .expect("edge has already been checked for zero length"),
-            None => Dir::from(Angle::new::<radian>(0.)),
-        }
+        self.edges
+            .iter()
+            .rev()
+            .find_map(|edge| edge.end_direction().ok())
+            .unwrap_or(Dir::from(Angle::new::<radian>(0.)))
+    }
+
+    /// Return the ruled surface that connects this `Path` with another one.
+    ///
+    /// Both paths are placed on their respective `Plane`s and connected by straight lines between
+    /// corresponding points. The resulting `Part` is an open shell without volume.
+    ///
+    /// Returns `Error::EmptyPath` if either of the paths does not contain any edges.
+    ///
+    /// ```rust
+    /// use anvil::{Error, IntoLength, Path, Plane, dir, point};
+    ///
+    /// let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+    /// let top = Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap();
+    /// assert!(path.loft_to(Plane::xy(), &path, top).is_ok());
+    ///
+    /// let empty = Path::at(point!(0, 0));
+    /// assert_eq!(path.loft_to(Plane::xy(), &empty, Plane::yz()), Err(Error::EmptyPath));
+    /// ```
+    pub fn loft_to(&self, plane: Plane, other: &Path, other_plane: Plane) -> Result<Part, Error> {
+        let wire = self.to_occt_wire(plane)?;
+        let other_wire = other.to_occt_wire(other_plane)?;
+
+        let mut loft = ffi::BRepOffsetAPI_ThruSections_ctor(false);
+        loft.pin_mut().AddWire(&wire);
+        loft.pin_mut().AddWire(&other_wire);
+        loft.pin_mut().CheckCompatibility(false);
+
+        Ok(Part::from_occt(loft.pin_mut().Shape()))
     }
 
     /// Return the edges in this `Path`.
@@ -197,28 +460,236 @@ impl Path {
         self.cursor
     }
 
-    fn add_edge(&self, edge: Edge) -> Self {
+    fn to_occt_wire(&self, plane: Plane) -> Result<UniquePtr<ffi::TopoDS_Wire>, Error> {
+        let occt_edges: Vec<UniquePtr<ffi::TopoDS_Edge>> = self
+            .edges
+            .iter()
+            .filter_map(|edge| edge.to_occt(plane))
+            .collect();
+
+        if occt_edges.is_empty() {
+            return Err(Error::EmptyPath);
+        }
+
+        let mut make_wire = ffi::BRepBuilderAPI_MakeWire_ctor();
+        for edge in occt_edges {
+            make_wire.pin_mut().add_edge(&edge)
+        }
+        Ok(ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire()))
+    }
+
+    /// Add an existing `Edge` to the end of this `Path`.
+    ///
+    /// Returns `Error::DiscontinuousPath` if the `Edge` does not start at the end of this `Path`.
+    ///
+    /// ```rust
+    /// use anvil::{Edge, Error, IntoLength, Path, point};
+    ///
+    /// let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+    /// let edge = Edge::Line(point!(1.m(), 0.m()), point!(1.m(), 1.m()));
+    /// assert_eq!(path.try_add_edge(edge), Ok(path.line_to(point!(1.m(), 1.m()))));
+    ///
+    /// let gap = Edge::Line(point!(2.m(), 0.m()), point!(2.m(), 1.m()));
+    /// assert_eq!(path.try_add_edge(gap), Err(Error::DiscontinuousPath));
+    /// ```
+    pub fn try_add_edge(&self, edge: Edge) -> Result<Self, Error> {
         if edge.start() != self.end() {
-            panic!("path is not continuous");
+            return Err(Error::DiscontinuousPath);
         }
 
         let new_cursor = edge.end();
         let mut new_edges = self.edges.clone();
         new_edges.push(edge);
 
-        Self {
+        Ok(Self {
             cursor: new_cursor,
             edges: new_edges,
-        }
+        })
+    }
+}
+impl Default for Path {
+    fn default() -> Self {
+        Self::at(Point::<2>::origin())
     }
 }
 
+/// Return the start, mid and end point of an arc rounding the corner between two lines.
+///
+/// Returns `None` if the corner can not be rounded.
+fn corner_fillet(
+    start: Point<2>,
+    corner: Point<2>,
+    end: Point<2>,
+    radius: Length,
+) -> Option<[Point<2>; 3]> {
+    let incoming = corner.direction_from(start).ok()?;
+    let outgoing = end.direction_from(corner).ok()?;
+    let turn = incoming.dot(outgoing).clamp(-1., 1.).acos();
+    if !(1e-9..=f64::consts::PI - 1e-9).contains(&turn) {
+        return None;
+    }
+
+    let tangent_length = radius * (turn / 2.).tan();
+    if tangent_length * 2. > corner.distance_to(start)
+        || tangent_length * 2. > end.distance_to(corner)
+    {
+        return None;
+    }
+
+    let bisector = (outgoing - incoming).ok()?;
+    let mid = corner + bisector * (radius / (turn / 2.).cos() - radius);
+    Some([
+        corner - incoming * tangent_length,
+        mid,
+        corner + outgoing * tangent_length,
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{IntoAngle, IntoLength, dir, point};
     use approx::assert_relative_eq;
 
+    #[test]
+    fn default_at_origin() {
+        let path = Path::default();
+        assert_eq!(path.start(), Point::<2>::origin());
+        assert!(path.edges().is_empty());
+    }
+
+    #[test]
+    fn smooth_square() {
+        let square = Path::at(point!(0, 0))
+            .line_to(point!(2.m(), 0.m()))
+            .line_to(point!(2.m(), 2.m()))
+            .line_to(point!(0.m(), 2.m()))
+            .line_to(point!(0, 0));
+        let smoothed = square.smooth(0.5.m());
+
+        assert_eq!(smoothed.edges().len(), 8);
+        assert_eq!(smoothed.start(), smoothed.end());
+        assert_relative_eq!(smoothed.start(), point!(0.5.m(), 0.m()));
+        assert_relative_eq!(
+            smoothed.edges()[1].point_at(0.5),
+            Point::<2>::new([
+                1.5.m() + 0.5.m() * f64::consts::FRAC_1_SQRT_2,
+                0.5.m() - 0.5.m() * f64::consts::FRAC_1_SQRT_2,
+            ]),
+            epsilon = 1e-9
+        );
+        for pair in smoothed.edges().windows(2) {
+            assert_eq!(pair[0].end(), pair[1].start());
+            assert_relative_eq!(
+                pair[0].tangent_at(1.).unwrap(),
+                pair[1].tangent_at(0.).unwrap(),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn smooth_square_area() {
+        let square = Path::at(point!(0, 0))
+            .line_to(point!(2.m(), 0.m()))
+            .line_to(point!(2.m(), 2.m()))
+            .line_to(point!(0.m(), 2.m()))
+            .line_to(point!(0, 0));
+        let area = square.smooth(0.5.m()).close().area().value;
+        assert_relative_eq!(area, 4. - (1. - f64::consts::FRAC_PI_4), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn smooth_skips_tight_corners() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(1.m(), 1.m()));
+        assert_eq!(path.smooth(0.6.m()), path);
+    }
+
+    #[test]
+    fn smooth_skips_straight_and_reversed_corners() {
+        let straight = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(2.m(), 0.m()));
+        assert_eq!(straight.smooth(0.1.m()), straight);
+
+        let reversed = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(0.m(), 0.m()));
+        assert_eq!(reversed.smooth(0.1.m()), reversed);
+    }
+
+    #[test]
+    fn smooth_non_positive_radius() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(1.m(), 1.m()));
+        assert_eq!(path.smooth(0.m()), path);
+        assert_eq!(path.smooth(-0.1.m()), path);
+    }
+
+    #[test]
+    fn smooth_open_path_keeps_ends() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(2.m(), 0.m()))
+            .line_to(point!(2.m(), 2.m()))
+            .line_to(point!(0.m(), 2.m()));
+        let smoothed = path.smooth(0.5.m());
+        assert_eq!(smoothed.edges().len(), 5);
+        assert_eq!(smoothed.start(), path.start());
+        assert_eq!(smoothed.end(), path.end());
+    }
+
+    #[test]
+    fn try_line_to_continuous() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        assert_eq!(
+            path.try_line_to(point!(1.m(), 1.m())),
+            Ok(path.line_to(point!(1.m(), 1.m())))
+        );
+    }
+
+    #[test]
+    fn try_add_edge_continuous() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        let arc = Edge::Arc(
+            point!(1.m(), 0.m()),
+            point!(2.m(), 1.m()),
+            point!(1.m(), 2.m()),
+        );
+        let extended = path.try_add_edge(arc.clone()).unwrap();
+        assert_eq!(extended.end(), point!(1.m(), 2.m()));
+        assert_eq!(extended.cursor(), point!(1.m(), 2.m()));
+        assert_eq!(extended.edges().last(), Some(&arc));
+    }
+
+    #[test]
+    fn try_add_edge_discontinuous() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        assert_eq!(
+            path.try_add_edge(Edge::Line(point!(5.m(), 5.m()), point!(1.m(), 1.m()))),
+            Err(Error::DiscontinuousPath)
+        );
+        assert_eq!(
+            path.try_add_edge(Edge::Arc(
+                point!(5.m(), 5.m()),
+                point!(6.m(), 6.m()),
+                point!(5.m(), 7.m())
+            )),
+            Err(Error::DiscontinuousPath)
+        );
+    }
+
+    #[test]
+    fn try_add_edge_to_empty_path() {
+        let path = Path::at(point!(1.m(), 1.m()));
+        assert_eq!(
+            path.try_add_edge(Edge::Line(point!(0, 0), point!(1.m(), 0.m()))),
+            Err(Error::DiscontinuousPath)
+        );
+    }
+
     #[test]
     fn end_arc_positive_radius_angle() {
         let path = Path::at(point!(0, 0)).arc_by(1.m(), 90.deg());
@@ -252,12 +723,93 @@ mod tests {
         )
     }
 
+    #[test]
+    fn tangent_arc_to_continues_line_direction() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 1.m()))
+            .tangent_arc_to(point!(3.m(), 1.m()));
+        assert_relative_eq!(path.end(), point!(3.m(), 1.m()));
+        assert_relative_eq!(path.end_direction(), dir!(1, -1), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tangent_arc_to_chained() {
+        let path = Path::at(point!(0, 0))
+            .tangent_arc_to(point!(1.m(), 1.m()))
+            .tangent_arc_to(point!(0.m(), 2.m()));
+        assert_relative_eq!(path.end_direction(), dir!(-1, 0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tangent_arc_to_behind_cursor() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .tangent_arc_to(point!(1.m(), 1.m()))
+            .tangent_arc_to(point!(2.m(), 2.m()));
+        assert_relative_eq!(path.end(), point!(2.m(), 2.m()));
+        assert_relative_eq!(path.end_direction(), dir!(0, -1), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tangent_arc_to_on_tangent() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        assert_eq!(
+            path.tangent_arc_to(point!(2.m(), 0.m())),
+            path.line_to(point!(2.m(), 0.m()))
+        );
+        assert_eq!(path.tangent_arc_to(point!(-1.m(), 0.m())), path);
+        assert_eq!(path.tangent_arc_to(point!(1.m(), 0.m())), path);
+    }
+
+    #[test]
+    fn spline_through_skips_duplicates() {
+        let path = Path::at(point!(0, 0)).spline_through(&[
+            point!(0, 0),
+            point!(1.m(), 1.m()),
+            point!(1.m(), 1.m()),
+            point!(2.m(), 0.m()),
+        ]);
+        assert_eq!(
+            path.edges(),
+            vec![Edge::Spline(vec![
+                point!(0, 0),
+                point!(1.m(), 1.m()),
+                point!(2.m(), 0.m())
+            ])]
+        );
+    }
+
+    #[test]
+    fn spline_through_no_points() {
+        let path = Path::at(point!(1.m(), 1.m()));
+        assert_eq!(path.spline_through(&[]), path);
+        assert_eq!(path.spline_through(&[point!(1.m(), 1.m())]), path);
+    }
+
     #[test]
     fn end_direction_empty_path() {
         let path = Path::at(point!(0, 0));
         assert_relative_eq!(path.end_direction(), dir!(1, 0))
     }
 
+    #[test]
+    fn end_direction_skips_degenerate_edges() {
+        let path = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 1.m()))
+            .line_to(point!(1.m(), 1.m()));
+        assert_relative_eq!(path.end_direction(), dir!(1, 1));
+
+        let path = Path::at(point!(1.m(), 1.m()))
+            .try_add_edge(Edge::Line(point!(1.m(), 1.m()), point!(1.m(), 1.m())))
+            .unwrap();
+        assert_relative_eq!(path.end_direction(), dir!(1, 0));
+        assert_relative_eq!(
+            path.arc_by(1.m(), 90.deg()).end(),
+            point!(2.m(), 2.m()),
+            epsilon = 1e-9
+        );
+    }
+
     #[test]
     fn end_direction_line() {
         let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 1.m()));
@@ -287,4 +839,32 @@ mod tests {
         let path = Path::at(point!(0, 0)).arc_by(-1.m(), -45.deg());
         assert_relative_eq!(path.end_direction(), dir!(-1, -1))
     }
+
+    #[test]
+    fn loft_to_parallel_lines() {
+        let path = Path::at(point!(0, 0)).line_to(point!(2.m(), 0.m()));
+        let top = Plane::new(point!(0.m(), 0.m(), 3.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap();
+        let band = path.loft_to(Plane::xy(), &path, top).unwrap();
+
+        let area: f64 = band
+            .faces()
+            .map(|face| face.area().get::<uom::si::area::square_meter>())
+            .sum();
+        assert_relative_eq!(area, 6., epsilon = 1e-9);
+        assert_relative_eq!(
+            band.volume().get::<uom::si::volume::cubic_meter>(),
+            0.,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn loft_to_empty_path() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        let empty = Path::at(point!(0, 0));
+        assert_eq!(
+            empty.loft_to(Plane::xy(), &path, Plane::xz()),
+            Err(Error::EmptyPath)
+        );
+    }
 }
@@ -31,6 +31,29 @@ impl Plane {
         Ok(Self(origin, x_dir, y_dir))
     }
 
+    /// Construct a `Plane` from a point and the normal direction.
+    ///
+    /// The x-axis of the plane is chosen perpendicular to the normal and the world axis that is
+    /// least aligned with it. The y-axis completes the right-handed coordinate system.
+    ///
+    /// ```rust
+    /// use anvil::{Plane, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let plane = Plane::from_normal(point!(0, 0, 0), dir!(1, 1, 0)).unwrap();
+    /// assert_relative_eq!(plane.normal(), dir!(1, 1, 0));
+    /// ```
+    pub fn from_normal(origin: Point<3>, normal: Dir<3>) -> Result<Self, Error> {
+        let least_aligned_axis = [dir!(1, 0, 0), dir!(0, 1, 0), dir!(0, 0, 1)]
+            .into_iter()
+            .min_by(|a, b| a.dot(normal).abs().total_cmp(&b.dot(normal).abs()))
+            .expect("array is not empty");
+        let perpendicular = least_aligned_axis.cross(normal);
+        let x_dir = Dir::<3>::try_from([perpendicular.x(), perpendicular.y(), perpendicular.z()])?;
+        let y_dir = normal.cross(x_dir);
+        Self::new(origin, x_dir, y_dir)
+    }
+
     /// Return the origin point of this `Plane`.
     pub fn origin(&self) -> Point<3> {
         self.0
@@ -60,3 +83,36 @@ impl Plane {
         (self.origin(), self.normal()).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_from_normal(normal: Dir<3>) {
+        let plane = Plane::from_normal(point!(0, 0, 0), normal).unwrap();
+        assert_relative_eq!(plane.normal(), normal, epsilon = 1e-9);
+        assert_relative_eq!(plane.x().dot(plane.y()), 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn from_normal_axis_aligned() {
+        assert_from_normal(dir!(1, 0, 0));
+        assert_from_normal(dir!(0, 1, 0));
+        assert_from_normal(dir!(0, 0, 1));
+        assert_from_normal(dir!(0, 0, -1));
+    }
+
+    #[test]
+    fn from_normal_near_axis_aligned() {
+        assert_from_normal(dir!(0.0001, 0, 1));
+        assert_from_normal(dir!(1, 0.0001, 0.0001));
+        assert_from_normal(dir!(-0.0001, -1, 0));
+    }
+
+    #[test]
+    fn from_normal_diagonal() {
+        assert_from_normal(dir!(1, 1, 1));
+        assert_from_normal(dir!(-1, 2, -3));
+    }
+}
@@ -1,3 +1,4 @@
+use std::iter::Sum;
 use std::ops::{Add, Div, Mul, Sub};
 
 use approx::{AbsDiffEq, RelativeEq};
@@ -6,7 +7,7 @@ use iter_fixed::IntoIteratorFixed;
 use opencascade_sys::ffi;
 use uom::si::length::meter;
 
-use crate::{Dir, Error, Length, Plane};
+use crate::{Dir, Error, Length, LengthExt, Plane};
 
 /// A location in space.
 ///
@@ -32,7 +33,7 @@ use crate::{Dir, Error, Length, Plane};
 /// );
 /// ```
 #[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
-pub struct Point<const DIM: usize>([Length; DIM]);
+pub struct Point<const DIM: usize>(pub(crate) [Length; DIM]);
 impl<const DIM: usize> Point<DIM> {
     /// Construct a `Point` from its coordinate `Length`s.
     ///
@@ -98,6 +99,97 @@ impl<const DIM: usize> Point<DIM> {
         ))
     }
 
+    /// Return the average of multiple `Point`s or `None` if no points are given.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Point, point};
+    ///
+    /// let corners = [
+    ///     point!(-1.m(), -1.m()),
+    ///     point!(1.m(), -1.m()),
+    ///     point!(1.m(), 1.m()),
+    ///     point!(-1.m(), 1.m()),
+    /// ];
+    /// assert_eq!(Point::centroid(&corners), Some(point!(0, 0)));
+    /// assert_eq!(Point::<3>::centroid(&[]), None);
+    /// ```
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        Some(points.iter().copied().sum::<Self>() / points.len() as f64)
+    }
+
+    /// Return the `Point` halfway between this one and another.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, point};
+    ///
+    /// // for 2D
+    /// assert_eq!(
+    ///     point!(1.m(), 2.m()).midpoint(point!(3.m(), 6.m())),
+    ///     point!(2.m(), 4.m())
+    /// );
+    ///
+    /// // for 3D
+    /// assert_eq!(
+    ///     point!(1.m(), 2.m(), 3.m()).midpoint(point!(3.m(), 6.m(), -3.m())),
+    ///     point!(2.m(), 4.m(), 0.m())
+    /// );
+    /// ```
+    pub fn midpoint(&self, other: Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+
+    /// Linearly interpolate between this `Point` and another.
+    ///
+    /// A factor of 0 returns this `Point` and a factor of 1 the other one. Factors outside of that
+    /// range extrapolate along the line through both points.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, point};
+    ///
+    /// // for 2D
+    /// let a = point!(0, 0);
+    /// let b = point!(4.m(), 2.m());
+    /// assert_eq!(a.lerp(b, 0.), a);
+    /// assert_eq!(a.lerp(b, 1.), b);
+    /// assert_eq!(a.lerp(b, 0.25), point!(1.m(), 0.5.m()));
+    /// assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+    ///
+    /// // for 3D
+    /// let a = point!(1.m(), 1.m(), 1.m());
+    /// let b = point!(3.m(), 5.m(), -1.m());
+    /// assert_eq!(a.lerp(b, 0.), a);
+    /// assert_eq!(a.lerp(b, 1.), b);
+    /// assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+    /// ```
+    pub fn lerp(&self, other: Self, t: f64) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Return a key based on the exact bit patterns of the coordinates of this `Point`.
+    ///
+    /// `Point`s can not implement `Hash` as their coordinates are floating point numbers. The key
+    /// can be used instead, for example to deduplicate points in a `HashMap`. Equal `Point`s have
+    /// equal keys.
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use anvil::{IntoLength, point};
+    ///
+    /// let points = [
+    ///     point!(1.m(), 2.m(), 3.m()),
+    ///     point!(4.m(), 5.m(), 6.m()),
+    ///     point!(1.m(), 2.m(), 3.m()),
+    /// ];
+    /// let unique: HashSet<_> = points.iter().map(|point| point.hash_key()).collect();
+    /// assert_eq!(unique.len(), 2);
+    /// ```
+    pub fn hash_key(&self) -> [u64; DIM] {
+        self.0.map(|coordinate| coordinate.hash_key())
+    }
+
     /// Return the direction this `Point` lies in with respect to another point.
     pub fn direction_from(&self, other: Self) -> Result<Dir<DIM>, Error> {
         Dir::<DIM>::try_from(
@@ -288,6 +380,20 @@ impl<const DIM: usize> Div<f64> for Point<DIM> {
     }
 }
 
+impl<const DIM: usize> Sum for Point<DIM> {
+    /// Add up all `Point`s of an iterator.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Point, point};
+    ///
+    /// let points = vec![point!(1.m(), 2.m()), point!(3.m(), 4.m()), point!(5.m(), 6.m())];
+    /// assert_eq!(points.into_iter().sum::<Point<2>>(), point!(9.m(), 12.m()));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::origin(), |sum, point| sum + point)
+    }
+}
+
 impl<const DIM: usize> AbsDiffEq for Point<DIM> {
     type Epsilon = f64;
     fn default_epsilon() -> Self::Epsilon {
@@ -0,0 +1,388 @@
+use approx::{AbsDiffEq, RelativeEq};
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+use uom::si::{angle::radian, length::meter};
+
+use crate::{Angle, Axis, Dir, Length, Plane, Point};
+
+/// A reusable transformation in 3D space.
+///
+/// A `Transform` can be built once from translations, rotations, scalings and mirrorings and then
+/// be applied to multiple `Part`s or `Sketch`es.
+///
+/// ```rust
+/// use anvil::{Axis, IntoAngle, IntoLength, Transform, point};
+/// use approx::assert_relative_eq;
+///
+/// let transform = Transform::translation(point!(1.m(), 0.m(), 0.m()))
+///     .then(Transform::rotation(Axis::<3>::z(), 90.deg()));
+/// assert_relative_eq!(
+///     transform.apply(point!(1.m(), 0.m(), 0.m())),
+///     point!(0.m(), 2.m(), 0.m())
+/// );
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Transform {
+    /// The rotational, scaling and mirroring part of the transformation.
+    matrix: [[f64; 3]; 3],
+    /// The translational part of the transformation in meters.
+    translation: [f64; 3],
+}
+impl Transform {
+    /// Construct a `Transform` that leaves everything unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Transform, point};
+    ///
+    /// let point = point!(1.m(), 2.m(), 3.m());
+    /// assert_eq!(Transform::identity().apply(point), point);
+    /// ```
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            translation: [0.; 3],
+        }
+    }
+
+    /// Construct a `Transform` that moves everything by an offset.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Transform, point};
+    ///
+    /// let transform = Transform::translation(point!(1.m(), 2.m(), 3.m()));
+    /// assert_eq!(
+    ///     transform.apply(point!(1.m(), 1.m(), 1.m())),
+    ///     point!(2.m(), 3.m(), 4.m())
+    /// );
+    /// ```
+    pub fn translation(offset: Point<3>) -> Self {
+        Self {
+            translation: to_meters(offset),
+            ..Self::identity()
+        }
+    }
+
+    /// Construct a `Transform` that rotates everything around an `Axis<3>`.
+    ///
+    /// For positive angles, the right-hand-rule applies for the direction of rotation.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoAngle, IntoLength, Transform, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let axis = Axis::<3>::new(point!(1.m(), 0.m(), 0.m()), dir!(0, 0, 1));
+    /// let transform = Transform::rotation(axis, 90.deg());
+    /// assert_relative_eq!(
+    ///     transform.apply(point!(2.m(), 0.m(), 5.m())),
+    ///     point!(1.m(), 1.m(), 5.m())
+    /// );
+    /// ```
+    pub fn rotation(axis: Axis<3>, angle: Angle) -> Self {
+        let (sin, cos) = angle.get::<radian>().sin_cos();
+        let k = axis.direction.0;
+        let cross = [[0., -k[2], k[1]], [k[2], 0., -k[0]], [-k[1], k[0], 0.]];
+
+        let mut matrix = [[0.; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (col, value) in matrix_row.iter_mut().enumerate() {
+                let identity = if row == col { 1. } else { 0. };
+                *value = cos * identity + sin * cross[row][col] + (1. - cos) * k[row] * k[col];
+            }
+        }
+        Self::with_fixed_point(matrix, axis.origin)
+    }
+
+    /// Construct a `Transform` that scales everything by a factor around a center `Point`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Transform, point};
+    ///
+    /// let transform = Transform::scale(point!(1.m(), 1.m(), 1.m()), 2.);
+    /// assert_eq!(
+    ///     transform.apply(point!(2.m(), 1.m(), 0.m())),
+    ///     point!(3.m(), 1.m(), -1.m())
+    /// );
+    /// ```
+    pub fn scale(center: Point<3>, factor: f64) -> Self {
+        let matrix = [[factor, 0., 0.], [0., factor, 0.], [0., 0., factor]];
+        Self::with_fixed_point(matrix, center)
+    }
+
+    /// Construct a `Transform` that mirrors everything about a `Plane`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Transform, point};
+    ///
+    /// let transform = Transform::mirror(Plane::xy());
+    /// assert_eq!(
+    ///     transform.apply(point!(1.m(), 2.m(), 3.m())),
+    ///     point!(1.m(), 2.m(), -3.m())
+    /// );
+    /// ```
+    pub fn mirror(plane: Plane) -> Self {
+        let n = plane.normal().0;
+        let mut matrix = [[0.; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (col, value) in matrix_row.iter_mut().enumerate() {
+                let identity = if row == col { 1. } else { 0. };
+                *value = identity - 2. * n[row] * n[col];
+            }
+        }
+        Self::with_fixed_point(matrix, plane.origin())
+    }
+
+    /// Return the `Transform` that first applies this one and then another.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, IntoAngle, IntoLength, Transform, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let translation = Transform::translation(point!(1.m(), 0.m(), 0.m()));
+    /// let rotation = Transform::rotation(Axis::<3>::z(), 90.deg());
+    /// let point = point!(1.m(), 1.m(), 1.m());
+    /// assert_relative_eq!(
+    ///     translation.then(rotation).apply(point),
+    ///     rotation.apply(translation.apply(point))
+    /// );
+    /// ```
+    pub fn then(&self, other: Self) -> Self {
+        let mut matrix = [[0.; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (col, value) in matrix_row.iter_mut().enumerate() {
+                *value = (0..3)
+                    .map(|i| other.matrix[row][i] * self.matrix[i][col])
+                    .sum();
+            }
+        }
+        Self {
+            matrix,
+            translation: other.apply_to_meters(self.translation),
+        }
+    }
+
+    /// Return a `Point` transformed by this `Transform`.
+    pub fn apply(&self, point: Point<3>) -> Point<3> {
+        let [x, y, z] = self.apply_to_meters(to_meters(point));
+        Point::<3>::new([
+            Length::new::<meter>(x),
+            Length::new::<meter>(y),
+            Length::new::<meter>(z),
+        ])
+    }
+
+    /// Return this `Transform` expressed in global coordinates, if it was defined in the local
+    /// coordinates of a `Plane`.
+    pub(crate) fn in_plane(&self, plane: Plane) -> Self {
+        let to_global = Self::to_plane(plane);
+        let to_local = Self {
+            matrix: transposed(to_global.matrix),
+            translation: [0.; 3],
+        };
+        let to_local = Self::translation(Point::<3>::origin() - plane.origin()).then(to_local);
+        to_local.then(*self).then(to_global)
+    }
+
+    /// Return the `Transform` that maps the xy-plane onto a `Plane`.
+    pub(crate) fn to_plane(plane: Plane) -> Self {
+        Self::from_basis(plane.x(), plane.y(), plane.normal(), plane.origin())
+    }
+
+    /// Return true if this `Transform` collapses space onto a plane, a line or a point, e.g. a
+    /// scaling by zero.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Transform, point};
+    ///
+    /// assert!(Transform::scale(point!(0, 0, 0), 0.).is_singular());
+    /// assert!(!Transform::scale(point!(0, 0, 0), 2.).is_singular());
+    /// ```
+    pub fn is_singular(&self) -> bool {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.matrix;
+        let determinant = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+        // OCCT rejects matrices with a determinant below its resolution by throwing
+        determinant.abs() <= f64::MIN_POSITIVE
+    }
+
+    /// Return the OCCT transformation of this `Transform`, or `None` if it is singular.
+    pub(crate) fn to_occt(self) -> Option<UniquePtr<ffi::gp_Trsf>> {
+        if self.is_singular() {
+            return None;
+        }
+        let [[a11, a12, a13], [a21, a22, a23], [a31, a32, a33]] = self.matrix;
+        let [a14, a24, a34] = self.translation;
+        let mut transform = ffi::new_transform();
+        transform
+            .pin_mut()
+            .SetValues(a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34);
+        Some(transform)
+    }
+
+    fn with_fixed_point(matrix: [[f64; 3]; 3], point: Point<3>) -> Self {
+        let point = to_meters(point);
+        let moved = Self {
+            matrix,
+            translation: [0.; 3],
+        }
+        .apply_to_meters(point);
+        Self {
+            matrix,
+            translation: [0, 1, 2].map(|i| point[i] - moved[i]),
+        }
+    }
+
+    fn from_basis(x: Dir<3>, y: Dir<3>, z: Dir<3>, origin: Point<3>) -> Self {
+        Self {
+            matrix: transposed([x.0, y.0, z.0]),
+            translation: to_meters(origin),
+        }
+    }
+
+    fn apply_to_meters(&self, point: [f64; 3]) -> [f64; 3] {
+        [0, 1, 2].map(|row| {
+            (0..3)
+                .map(|col| self.matrix[row][col] * point[col])
+                .sum::<f64>()
+                + self.translation[row]
+        })
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AbsDiffEq for Transform {
+    type Epsilon = f64;
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.matrix
+            .iter()
+            .flatten()
+            .chain(self.translation.iter())
+            .zip(
+                other
+                    .matrix
+                    .iter()
+                    .flatten()
+                    .chain(other.translation.iter()),
+            )
+            .all(|(a, b)| f64::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl RelativeEq for Transform {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.matrix
+            .iter()
+            .flatten()
+            .chain(self.translation.iter())
+            .zip(
+                other
+                    .matrix
+                    .iter()
+                    .flatten()
+                    .chain(other.translation.iter()),
+            )
+            .all(|(a, b)| f64::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+fn to_meters(point: Point<3>) -> [f64; 3] {
+    point.0.map(|coordinate| coordinate.get::<meter>())
+}
+
+fn transposed(matrix: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    [0, 1, 2].map(|row| [0, 1, 2].map(|col| matrix[col][row]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoAngle, IntoLength, dir, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn then_matches_sequential_application() {
+        let translation = Transform::translation(point!(1.m(), 2.m(), 3.m()));
+        let rotation = Transform::rotation(
+            Axis::<3>::new(point!(0.m(), 1.m(), 0.m()), dir!(1, 1, 0)),
+            30.deg(),
+        );
+        let point = point!(4.m(), -2.m(), 0.5.m());
+        assert_relative_eq!(
+            translation.then(rotation).apply(point),
+            rotation.apply(translation.apply(point)),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            rotation.then(translation).apply(point),
+            translation.apply(rotation.apply(point)),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn then_identity() {
+        let rotation = Transform::rotation(Axis::<3>::x(), 45.deg());
+        assert_relative_eq!(rotation.then(Transform::identity()), rotation);
+        assert_relative_eq!(Transform::identity().then(rotation), rotation);
+    }
+
+    #[test]
+    fn mirror_twice_is_identity() {
+        let plane = Plane::from_normal(point!(1.m(), 2.m(), 3.m()), dir!(1, -2, 1)).unwrap();
+        let mirror = Transform::mirror(plane);
+        assert_relative_eq!(mirror.then(mirror), Transform::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn mirror_keeps_plane_points() {
+        let plane = Plane::from_normal(point!(1.m(), 2.m(), 3.m()), dir!(0, 1, 1)).unwrap();
+        let point = plane.origin() + plane.x() * 2.m();
+        assert_relative_eq!(
+            Transform::mirror(plane).apply(point),
+            point,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn rotation_full_turn_is_identity() {
+        let rotation = Transform::rotation(Axis::<3>::y(), 360.deg());
+        assert_relative_eq!(rotation, Transform::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn in_plane_rotation() {
+        let plane = Plane::xz();
+        let rotation = Transform::rotation(Axis::<3>::z(), 90.deg());
+        assert_relative_eq!(
+            rotation.in_plane(plane).apply(point!(1.m(), 0.m(), 0.m())),
+            point!(0.m(), 0.m(), 1.m()),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn in_plane_translation() {
+        let plane = Plane::new(point!(1.m(), 1.m(), 1.m()), dir!(0, 1, 0), dir!(0, 0, 1)).unwrap();
+        let translation = Transform::translation(point!(2.m(), 3.m(), 0.m()));
+        assert_relative_eq!(
+            translation.in_plane(plane),
+            Transform::translation(point!(0.m(), 2.m(), 3.m())),
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{Dir, Error, IntoLength, Length, Point};
+
+/// A bounded curve in 3D space, e.g. where two faces of a `Part` meet or along a `Helix`.
+///
+/// Unlike an `Edge`, which is used to construct a `Sketch`, an `Edge3D` is read from an existing
+/// `Part` or `Helix` and lives in 3D space.
+pub struct Edge3D(pub(crate) UniquePtr<ffi::TopoDS_Edge>);
+impl Edge3D {
+    /// Return the starting point of this `Edge3D`.
+    pub fn start(&self) -> Point<3> {
+        self.point_at(0.)
+    }
+
+    /// Return the end point of this `Edge3D`.
+    ///
+    /// For closed edges like circles, this is the same as the start point.
+    pub fn end(&self) -> Point<3> {
+        self.point_at(1.)
+    }
+
+    /// Return the length of this `Edge3D` along its curve.
+    pub fn len(&self) -> Length {
+        let mut gprops = ffi::GProp_GProps_ctor();
+        ffi::BRepGProp_LinearProperties(ffi::cast_edge_to_shape(&self.0), gprops.pin_mut());
+        Length::new::<meter>(gprops.Mass())
+    }
+
+    /// Return the direction from the start to the end point of this `Edge3D`.
+    ///
+    /// If both points coincide, e.g. for a closed circle, an `Error::ZeroVector` is returned.
+    pub fn direction(&self) -> Result<Dir<3>, Error> {
+        self.end().direction_from(self.start())
+    }
+
+    pub(crate) fn from_occt(occt: &ffi::TopoDS_Edge) -> Self {
+        Self(ffi::TopoDS_Edge_to_owned(occt))
+    }
+
+    /// Evaluate the curve of this `Edge3D` at a normalized parameter between 0 and 1.
+    fn point_at(&self, t: f64) -> Point<3> {
+        let curve = ffi::BRepAdaptor_Curve_ctor(&self.0);
+        let (first, last) = (curve.FirstParameter(), curve.LastParameter());
+        let point = ffi::BRepAdaptor_Curve_value(&curve, first + (last - first) * t);
+        Point::<3>::new([point.X().m(), point.Y().m(), point.Z().m()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cube, Cylinder, Part};
+
+    fn edges(part: &Part) -> Vec<Edge3D> {
+        let inner = part.inner.as_ref().unwrap();
+        let mut explorer = ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_EDGE);
+        let mut edges = vec![];
+        while explorer.More() {
+            edges.push(Edge3D::from_occt(ffi::TopoDS_cast_to_edge(
+                explorer.Current(),
+            )));
+            explorer.pin_mut().Next();
+        }
+        edges
+    }
+
+    #[test]
+    fn cube_edges() {
+        for edge in edges(&Cube::from_size(2.m())) {
+            assert_relative_eq!(edge.len().value, 2.);
+            assert_relative_eq!(edge.start().distance_to(edge.end()).value, 2.);
+            let direction = edge.direction().unwrap();
+            assert_relative_eq!(
+                direction.x().abs() + direction.y().abs() + direction.z().abs(),
+                1.
+            );
+        }
+    }
+
+    #[test]
+    fn closed_circles_have_no_direction() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let closed: Vec<Edge3D> = edges(&cylinder)
+            .into_iter()
+            .filter(|edge| edge.direction().is_err())
+            .collect();
+        assert!(!closed.is_empty());
+        for edge in closed {
+            assert_relative_eq!(edge.len().value, std::f64::consts::TAU, max_relative = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod edge_3d;
+
+pub use edge_3d::Edge3D;
@@ -5,15 +5,54 @@ use crate::Dir;
 /// The errors that can occurr.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
+    /// Occurs when a .brep file at a given path could not be read.
+    BrepRead(PathBuf),
+
+    /// Occurs when a `Part` could not be written to a .brep file at a given path.
+    BrepWrite(PathBuf),
+
+    /// Occurs when the edges of a `Part` can not be chamfered.
+    Chamfer,
+
+    /// Occurs when the convex hull of a `Part` has no volume, e.g. because all of its points lie
+    /// in a common plane, or can not be computed consistently.
+    DegenerateHull,
+
+    /// Occurs when an edge is added to a `Path` that does not start at the end of the `Path`.
+    DiscontinuousPath,
+
     /// Occurs when a function that requires a non-empty `Part` is called on an empty one.
     EmptyPart,
 
+    /// Occurs when a function that requires a non-empty `Path` is called on an empty one.
+    EmptyPath,
+
     /// Occurs when a function that requires a non-empty `Sketch` is called on an empty one.
     EmptySketch,
 
+    /// Occurs when the edges of a `Sketch` do not form a valid face, e.g. because they intersect
+    /// themselves.
+    InvalidWire,
+
+    /// Occurs when a .obj file at a given path could not be read.
+    ObjRead(PathBuf),
+
+    /// Occurs when an offset operation like thickening a `Face` or hollowing a `Part` fails.
+    Offset,
+
+    /// Occurs when a direction that is required to leave a `Plane`, e.g. to extrude a `Sketch`
+    /// along it, is parallel to the `Plane`.
+    ParallelToPlane(Dir<3>),
+
+    /// Occurs when a `Transform` that collapses space, e.g. a scaling by zero, is applied.
+    SingularTransform,
+
     /// Occurs when a `Part` could not be written to a .step file at a given path.
     StepWrite(PathBuf),
 
+    /// Occurs when a .stl file at a given path could not be read.
+    StlRead(PathBuf),
+
     /// Occurs when a `Part` could not be written to a .stl file at a given path.
     StlWrite(PathBuf),
 
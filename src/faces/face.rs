@@ -1,9 +1,144 @@
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
+use uom::si::area::square_meter;
+use uom::si::f64::Area;
+use uom::si::length::meter;
+
+use crate::{Dir, Error, IntoLength, Length, Part, Plane, Point};
 
 /// A 2D surface that has a clear bound.
 pub struct Face(pub(crate) UniquePtr<ffi::TopoDS_Face>);
 impl Face {
+    /// Return the area of this `Face` in square meters.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let face = Rectangle::from_dim(2.m(), 3.m()).to_face(Plane::xy()).unwrap();
+    /// assert_relative_eq!(face.area().value, 6.);
+    /// ```
+    pub fn area(&self) -> Area {
+        let mut gprops = ffi::GProp_GProps_ctor();
+        ffi::BRepGProp_SurfaceProperties(ffi::cast_face_to_shape(&self.0), gprops.pin_mut());
+        Area::new::<square_meter>(gprops.Mass())
+    }
+
+    /// Return the center of mass of this `Face`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let face = Rectangle::from_dim(2.m(), 3.m())
+    ///     .move_to(point!(1.m(), 1.m()))
+    ///     .to_face(Plane::xz())
+    ///     .unwrap();
+    /// assert_relative_eq!(face.center(), point!(1.m(), 0.m(), 1.m()));
+    /// ```
+    pub fn center(&self) -> Point<3> {
+        let mut gprops = ffi::GProp_GProps_ctor();
+        ffi::BRepGProp_SurfaceProperties(ffi::cast_face_to_shape(&self.0), gprops.pin_mut());
+
+        let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&gprops);
+        Point::<3>::new([
+            centre_of_mass.X().m(),
+            centre_of_mass.Y().m(),
+            centre_of_mass.Z().m(),
+        ])
+    }
+
+    /// Return the outward facing normal of this `Face` at the center of its parameter range.
+    ///
+    /// For planar faces, this is the normal of the whole `Face`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle, dir};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let face = Rectangle::from_dim(2.m(), 3.m()).to_face(Plane::xy()).unwrap();
+    /// assert_relative_eq!(face.normal_at_center(), dir!(0, 0, 1));
+    /// ```
+    pub fn normal_at_center(&self) -> Dir<3> {
+        let surface = ffi::BRepGProp_Face_ctor(&self.0);
+        let (mut u_min, mut u_max, mut v_min, mut v_max) = (0., 0., 0., 0.);
+        surface.Bounds(&mut u_min, &mut u_max, &mut v_min, &mut v_max);
+
+        let mut point = ffi::new_point(0., 0., 0.);
+        let mut normal = ffi::new_vec(0., 0., 0.);
+        surface.Normal(
+            (u_min + u_max) / 2.,
+            (v_min + v_max) / 2.,
+            point.pin_mut(),
+            normal.pin_mut(),
+        );
+        Dir::try_from([normal.X(), normal.Y(), normal.Z()])
+            .expect("normal of a bounded face is not zero")
+    }
+
+    /// Convert this `Face` into a `Part` by giving it a thickness along its normal.
+    ///
+    /// Negative thicknesses grow the `Part` in the opposite direction of the normal.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let face = Rectangle::from_dim(2.m(), 2.m()).to_face(Plane::xy()).unwrap();
+    /// let slab = face.thicken(0.1.m()).unwrap();
+    /// assert_relative_eq!(slab.volume().value, 0.4, max_relative = 1e-7);
+    /// ```
+    pub fn thicken(&self, thickness: Length) -> Result<Part, Error> {
+        if thickness == Length::new::<meter>(0.) {
+            return Err(Error::Offset);
+        }
+
+        let closing_faces = ffi::new_list_of_shape();
+        let mut make_thick_solid = ffi::BRepOffsetAPI_MakeThickSolid_ctor();
+        ffi::MakeThickSolidByJoin(
+            make_thick_solid.pin_mut(),
+            ffi::cast_face_to_shape(&self.0),
+            &closing_faces,
+            thickness.get::<meter>(),
+            1e-7,
+        );
+        if !make_thick_solid.IsDone() {
+            return Err(Error::Offset);
+        }
+        Ok(Part::from_occt(make_thick_solid.pin_mut().Shape()))
+    }
+
+    /// Return true if every point of the underlying surface of this `Face` lies within a tolerance
+    /// of a `Plane`.
+    ///
+    /// The surface is sampled on a grid across its parameter range, which covers planar faces
+    /// regardless of the shape of their boundary.
+    pub(crate) fn lies_on(&self, plane: Plane, tolerance: Length) -> bool {
+        let surface = ffi::BRepGProp_Face_ctor(&self.0);
+        let (mut u_min, mut u_max, mut v_min, mut v_max) = (0., 0., 0., 0.);
+        surface.Bounds(&mut u_min, &mut u_max, &mut v_min, &mut v_max);
+
+        let normal = plane.normal();
+        let samples = [0., 0.25, 0.5, 0.75, 1.];
+        samples.iter().all(|s| {
+            samples.iter().all(|t| {
+                let mut point = ffi::new_point(0., 0., 0.);
+                let mut surface_normal = ffi::new_vec(0., 0., 0.);
+                surface.Normal(
+                    u_min + (u_max - u_min) * s,
+                    v_min + (v_max - v_min) * t,
+                    point.pin_mut(),
+                    surface_normal.pin_mut(),
+                );
+                let offset =
+                    Point::<3>::new([point.X().m(), point.Y().m(), point.Z().m()]) - plane.origin();
+                let distance =
+                    offset.x() * normal.x() + offset.y() * normal.y() + offset.z() * normal.z();
+                distance.abs() <= tolerance
+            })
+        })
+    }
+
     pub(crate) fn from_occt(occt: &ffi::TopoDS_Face) -> Self {
         Self(ffi::TopoDS_Face_to_owned(occt))
     }
@@ -14,3 +149,62 @@ impl Clone for Face {
         Self::from_occt(&self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cube, IntoLength, Plane, Rectangle, dir, point};
+
+    #[test]
+    fn cube_faces_area_and_normals() {
+        let faces: Vec<Face> = Cube::from_size(2.m()).faces().collect();
+        assert_eq!(faces.len(), 6);
+        for face in &faces {
+            assert_relative_eq!(face.area().value, 4.);
+        }
+
+        let mut normals: Vec<Dir<3>> = faces.iter().map(|face| face.normal_at_center()).collect();
+        for expected in [
+            dir!(1, 0, 0),
+            dir!(-1, 0, 0),
+            dir!(0, 1, 0),
+            dir!(0, -1, 0),
+            dir!(0, 0, 1),
+            dir!(0, 0, -1),
+        ] {
+            let position = normals
+                .iter()
+                .position(|normal| normal.dot(expected) > 1. - 1e-9)
+                .expect("every cardinal normal should be present once");
+            normals.remove(position);
+        }
+    }
+
+    #[test]
+    fn cube_face_centers() {
+        for face in Cube::from_size(2.m()).faces() {
+            let offset = face.normal_at_center() * 1.m();
+            assert_relative_eq!(face.center(), offset);
+        }
+    }
+
+    #[test]
+    fn thicken_planar_face() {
+        let face = Rectangle::from_dim(2.m(), 2.m())
+            .to_face(Plane::xy())
+            .unwrap();
+        let slab = face.thicken(0.1.m()).unwrap();
+        assert_relative_eq!(slab.volume().value, 0.4, max_relative = 1e-7);
+        assert_relative_eq!(slab.center().unwrap(), point!(0.m(), 0.m(), 0.05.m()));
+    }
+
+    #[test]
+    fn thicken_zero() {
+        let face = Rectangle::from_dim(2.m(), 2.m())
+            .to_face(Plane::xy())
+            .unwrap();
+        assert!(face.thicken(0.m()).is_err());
+    }
+}
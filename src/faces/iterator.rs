@@ -16,8 +16,8 @@ use super::face::Face;
 /// }
 /// ```
 pub enum FaceIterator {
-    /// A FaceIterator that is not empty.
-    NotEmpty(Part, UniquePtr<ffi::TopExp_Explorer>),
+    /// A FaceIterator that is not empty, together with the number of faces it has left.
+    NotEmpty(Part, UniquePtr<ffi::TopExp_Explorer>, usize),
     /// A FaceIterator from an empty shape.
     Empty,
 }
@@ -27,11 +27,12 @@ impl Iterator for FaceIterator {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
-            Self::NotEmpty(_, explorer) => {
+            Self::NotEmpty(_, explorer, remaining) => {
                 if explorer.More() {
                     let face = ffi::TopoDS_cast_to_face(explorer.Current());
                     let face = Face::from_occt(face);
                     explorer.pin_mut().Next();
+                    *remaining -= 1;
                     Some(face)
                 } else {
                     None
@@ -40,39 +41,28 @@ impl Iterator for FaceIterator {
             Self::Empty => None,
         }
     }
-}
-impl ExactSizeIterator for FaceIterator {
-    fn len(&self) -> usize {
-        match self {
-            Self::NotEmpty(_, _) => {
-                let mut len = 0;
-                for _ in self.clone_without_position() {
-                    len += 1;
-                }
-                len
-            }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self {
+            Self::NotEmpty(_, _, remaining) => *remaining,
             Self::Empty => 0,
-        }
+        };
+        (remaining, Some(remaining))
     }
 }
+impl ExactSizeIterator for FaceIterator {}
 impl FaceIterator {
     /// Return `true` if this `FaceIterator` has a length of 0.
-    pub fn is_empty(self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    fn clone_without_position(&self) -> Self {
-        match self {
-            Self::NotEmpty(part, _) => part.faces(),
-            Self::Empty => Self::Empty,
-        }
-    }
 }
 impl From<&Part> for FaceIterator {
     fn from(value: &Part) -> Self {
         match &value.inner {
             Some(inner) => {
                 let explorer = ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
-                Self::NotEmpty(value.clone(), explorer)
+                Self::NotEmpty(value.clone(), explorer, value.face_count())
             }
             None => Self::Empty,
         }
@@ -82,9 +72,24 @@ impl From<&Part> for FaceIterator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Cube, IntoLength};
 
     #[test]
     fn empty() {
         assert!(Part::empty().faces().is_empty())
     }
+
+    #[test]
+    fn is_empty_does_not_consume() {
+        let faces = Part::empty().faces();
+        assert!(faces.is_empty());
+        assert_eq!(faces.count(), 0);
+
+        let mut faces = Cube::from_size(1.m()).faces();
+        assert_eq!(faces.len(), 6);
+        faces.next();
+        assert_eq!(faces.len(), 5);
+        assert!(!faces.is_empty());
+        assert_eq!(faces.count(), 5);
+    }
 }
@@ -5,6 +5,7 @@
 #![warn(clippy::unimplemented)]
 
 mod core;
+mod edges;
 mod errors;
 mod faces;
 mod meshes;
@@ -12,16 +13,18 @@ mod parts;
 mod sketches;
 
 pub use core::{
-    Angle, Axis, Dir, Edge, IntoAngle, IntoF64, IntoLength, Length, Path, Plane, Point,
+    Angle, AngleExt, Axis, Dir, Edge, Helix, IntoAngle, IntoF64, IntoLength, Length, LengthExt,
+    Path, Plane, Point, Transform,
 };
+pub use edges::Edge3D;
 pub use errors::Error;
 pub use faces::{Face, FaceIterator};
-pub use meshes::RenderMesh;
+pub use meshes::{IndexedMesh, MeshOptions, RenderMesh, UvMode, Vertex};
 pub use parts::{
-    Part,
-    primitives::{Cube, Cuboid, Cylinder, Sphere},
+    Part, Topology,
+    primitives::{Capsule, Cube, Cuboid, Cylinder, Ellipsoid, Pyramid, Sphere},
 };
 pub use sketches::{
     Sketch,
-    primitives::{Circle, Rectangle, Square},
+    primitives::{Circle, Rectangle, Square, Star},
 };
@@ -0,0 +1,75 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology};
+use uom::si::length::meter;
+
+use crate::RenderMesh;
+
+impl From<RenderMesh> for Mesh {
+    /// Convert a `RenderMesh` into a bevy `Mesh`.
+    ///
+    /// Positions are given in meters.
+    fn from(value: RenderMesh) -> Self {
+        let positions: Vec<[f32; 3]> = value
+            .points()
+            .iter()
+            .map(|point| {
+                [
+                    point.x().get::<meter>() as f32,
+                    point.y().get::<meter>() as f32,
+                    point.z().get::<meter>() as f32,
+                ]
+            })
+            .collect();
+        let normals: Vec<[f32; 3]> = value
+            .normals()
+            .iter()
+            .map(|normal| [normal.x() as f32, normal.y() as f32, normal.z() as f32])
+            .collect();
+        let uvs: Vec<[f32; 2]> = value
+            .uvs()
+            .iter()
+            .map(|[u, v]| [*u as f32, *v as f32])
+            .collect();
+        let indices: Vec<u32> = value
+            .indices()
+            .iter()
+            .flatten()
+            .map(|index| *index as u32)
+            .collect();
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cube, IntoLength};
+
+    #[test]
+    fn attribute_counts() {
+        let render_mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+        let mesh = Mesh::from(render_mesh.clone());
+
+        assert_eq!(mesh.count_vertices(), render_mesh.points().len());
+        assert_eq!(
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap().len(),
+            render_mesh.normals().len()
+        );
+        assert_eq!(
+            mesh.attribute(Mesh::ATTRIBUTE_UV_0).unwrap().len(),
+            render_mesh.uvs().len()
+        );
+        assert_eq!(
+            mesh.indices().unwrap().len(),
+            3 * render_mesh.indices().len()
+        );
+    }
+}
@@ -0,0 +1,612 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::{Dir, Error, IntoLength, Point, RenderMesh};
+
+/// A triangular mesh in which triangles reference shared `Point`s by their index.
+///
+/// Unlike a `RenderMesh`, which duplicates `Point`s on the border between `Face`s, every location
+/// is only stored once. Normals and uvs are optional and empty if the source did not contain any.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexedMesh {
+    points: Vec<Point<3>>,
+    indices: Vec<[usize; 3]>,
+    normals: Vec<Dir<3>>,
+    uvs: Vec<[f64; 2]>,
+}
+impl IndexedMesh {
+    /// Read an `IndexedMesh` from a file in the binary or ASCII STL format.
+    ///
+    /// Coordinates are interpreted as meters. Vertices that share the same location are merged
+    /// into a single `Point`. Since STL only provides normals per triangle, the normals of the
+    /// resulting `IndexedMesh` are empty.
+    pub fn read_stl(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|_| Error::StlRead(path.into()))?;
+
+        let triangles = if is_binary_stl(&bytes) {
+            parse_binary_stl(&bytes)
+        } else {
+            parse_ascii_stl(&bytes)
+        };
+        match triangles {
+            Some(triangles) => {
+                let triangles: Vec<[Point<3>; 3]> = triangles
+                    .iter()
+                    .map(|triangle| {
+                        triangle.map(|[x, y, z]| Point::<3>::new([x.m(), y.m(), z.m()]))
+                    })
+                    .collect();
+                Ok(Self::from_triangles(&triangles))
+            }
+            None => Err(Error::StlRead(path.into())),
+        }
+    }
+
+    /// Read an `IndexedMesh` from a file in the Wavefront OBJ format.
+    ///
+    /// Vertex positions (`v`), texture coordinates (`vt`), normals (`vn`) and faces (`f`) are
+    /// parsed, all other statements are ignored. Faces with more than three vertices are split into
+    /// triangles. Coordinates are interpreted as meters.
+    ///
+    /// Normals and uvs are only populated if every face vertex references one, otherwise they are
+    /// left empty.
+    pub fn read_obj(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|_| Error::ObjRead(path.into()))?;
+        parse_obj(&text).ok_or(Error::ObjRead(path.into()))
+    }
+
+    /// Construct an `IndexedMesh` from a `RenderMesh` by merging the `Point`s that are duplicated
+    /// on the borders between `Face`s.
+    ///
+    /// This allows using a single triangulation for both mesh types. As merged `Point`s can belong
+    /// to `Face`s with different normals, the normals and uvs of the resulting `IndexedMesh` are
+    /// empty.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IndexedMesh, IntoLength, RenderMesh};
+    ///
+    /// let render_mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// let indexed_mesh = IndexedMesh::from_render_mesh(&render_mesh);
+    /// assert_eq!(render_mesh.points().len(), 24);
+    /// assert_eq!(indexed_mesh.points().len(), 8);
+    /// assert_eq!(indexed_mesh.indices().len(), render_mesh.indices().len());
+    /// ```
+    pub fn from_render_mesh(mesh: &RenderMesh) -> Self {
+        let triangles: Vec<[Point<3>; 3]> = mesh
+            .indices()
+            .iter()
+            .map(|triangle| triangle.map(|index| mesh.points()[index]))
+            .collect();
+        Self::from_triangles(&triangles)
+    }
+
+    /// Return the `Point`s of this `IndexedMesh`.
+    pub fn points(&self) -> &Vec<Point<3>> {
+        &self.points
+    }
+    /// Return the `Point` indices defining the triangles of this `IndexedMesh`.
+    pub fn indices(&self) -> &Vec<[usize; 3]> {
+        &self.indices
+    }
+    /// Return the normal `Dir` of every `Point` in this `IndexedMesh`.
+    pub fn normals(&self) -> &Vec<Dir<3>> {
+        &self.normals
+    }
+    /// Return the relative position of every `Point` on the 2D-grid of this `IndexedMesh`.
+    pub fn uvs(&self) -> &Vec<[f64; 2]> {
+        &self.uvs
+    }
+
+    /// Return the minimum and maximum corner of the axis-aligned box enclosing this
+    /// `IndexedMesh`, or `None` if it does not contain any points.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, point};
+    ///
+    /// let mesh = Cuboid::from_dim(1.m(), 2.m(), 3.m()).triangulate().unwrap();
+    /// assert_eq!(
+    ///     mesh.bounding_box(),
+    ///     Some((point!(-0.5.m(), -1.m(), -1.5.m()), point!(0.5.m(), 1.m(), 1.5.m())))
+    /// );
+    /// ```
+    pub fn bounding_box(&self) -> Option<(Point<3>, Point<3>)> {
+        let first = *self.points.first()?;
+        Some(
+            self.points
+                .iter()
+                .fold((first, first), |(min, max), point| {
+                    (
+                        Point::<3>::new([0, 1, 2].map(|i| min.0[i].min(point.0[i]))),
+                        Point::<3>::new([0, 1, 2].map(|i| max.0[i].max(point.0[i]))),
+                    )
+                }),
+        )
+    }
+    /// Return the center point of the `IndexedMesh`, i.e. the average of all mesh points.
+    ///
+    /// As every location is only stored once, this can differ from the center of a `RenderMesh`
+    /// of the same shape. If the `IndexedMesh` does not contain any points, the origin is
+    /// returned.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, point};
+    ///
+    /// let cube = Cube::from_size(2.m()).move_to(point!(1.m(), 2.m(), 3.m()));
+    /// let mesh = cube.triangulate().unwrap();
+    /// assert_eq!(mesh.center(), point!(1.m(), 2.m(), 3.m()));
+    /// ```
+    pub fn center(&self) -> Point<3> {
+        Point::centroid(&self.points).unwrap_or(Point::<3>::origin())
+    }
+
+    fn from_triangles(triangles: &[[Point<3>; 3]]) -> Self {
+        let mut points = vec![];
+        let mut indices = vec![];
+        let mut point_indices: HashMap<[u64; 3], usize> = HashMap::new();
+
+        for triangle in triangles {
+            let triangle_indices = triangle.map(|point| {
+                *point_indices.entry(point.hash_key()).or_insert_with(|| {
+                    points.push(point);
+                    points.len() - 1
+                })
+            });
+            indices.push(triangle_indices);
+        }
+
+        Self {
+            points,
+            indices,
+            normals: vec![],
+            uvs: vec![],
+        }
+    }
+}
+impl From<RenderMesh> for IndexedMesh {
+    /// Convert a `RenderMesh` into an `IndexedMesh` without merging any `Point`s.
+    ///
+    /// Unlike `IndexedMesh::from_render_mesh()`, the `Point`s on the borders between `Face`s stay
+    /// duplicated, which keeps the normals and uvs of the `RenderMesh`.
+    fn from(mesh: RenderMesh) -> Self {
+        Self {
+            points: mesh.points().clone(),
+            indices: mesh.indices().clone(),
+            normals: mesh.normals().clone(),
+            uvs: mesh.uvs().clone(),
+        }
+    }
+}
+
+fn is_binary_stl(bytes: &[u8]) -> bool {
+    if bytes.len() < 84 {
+        return false;
+    }
+    let triangle_count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+    bytes.len() == 84 + 50 * triangle_count
+}
+
+fn parse_binary_stl(bytes: &[u8]) -> Option<Vec<[[f64; 3]; 3]>> {
+    let read_f32 = |offset: usize| -> Option<f64> {
+        let value_bytes = bytes.get(offset..offset + 4)?;
+        Some(f32::from_le_bytes(value_bytes.try_into().ok()?) as f64)
+    };
+
+    let mut triangles = vec![];
+    // every triangle consists of a normal, three vertices and an attribute byte count
+    for triangle_start in (84..bytes.len()).step_by(50) {
+        let mut triangle = [[0.; 3]; 3];
+        for (vertex_index, vertex) in triangle.iter_mut().enumerate() {
+            let vertex_start = triangle_start + 12 + 12 * vertex_index;
+            *vertex = [
+                read_f32(vertex_start)?,
+                read_f32(vertex_start + 4)?,
+                read_f32(vertex_start + 8)?,
+            ];
+        }
+        triangles.push(triangle);
+    }
+    Some(triangles)
+}
+
+fn parse_ascii_stl(bytes: &[u8]) -> Option<Vec<[[f64; 3]; 3]>> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    let mut tokens = text.split_whitespace();
+    if tokens.next() != Some("solid") {
+        return None;
+    }
+
+    let mut vertices = vec![];
+    while let Some(token) = tokens.next() {
+        if token == "vertex" {
+            let mut vertex = [0.; 3];
+            for coordinate in &mut vertex {
+                *coordinate = tokens.next()?.parse().ok()?;
+            }
+            vertices.push(vertex);
+        }
+    }
+
+    if vertices.len() % 3 != 0 {
+        return None;
+    }
+    Some(
+        vertices
+            .chunks_exact(3)
+            .map(|chunk| [chunk[0], chunk[1], chunk[2]])
+            .collect(),
+    )
+}
+
+/// The indices of the position, uv and normal of a single vertex in an OBJ file.
+type ObjVertex = (usize, Option<usize>, Option<usize>);
+
+fn parse_obj(text: &str) -> Option<IndexedMesh> {
+    let mut positions: Vec<[f64; 3]> = vec![];
+    let mut uvs: Vec<[f64; 2]> = vec![];
+    let mut normals: Vec<[f64; 3]> = vec![];
+    let mut faces: Vec<Vec<ObjVertex>> = vec![];
+
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => positions.push(parse_obj_floats(tokens)?),
+            Some("vt") => {
+                let [u, v] = parse_obj_floats(tokens.chain(["0"]))?;
+                uvs.push([u, v]);
+            }
+            Some("vn") => normals.push(parse_obj_floats(tokens)?),
+            Some("f") => {
+                let face = tokens
+                    .map(|token| parse_obj_vertex(token, positions.len(), uvs.len(), normals.len()))
+                    .collect::<Option<Vec<_>>>()?;
+                if face.len() < 3 {
+                    return None;
+                }
+                faces.push(face);
+            }
+            _ => (),
+        }
+    }
+
+    let mut mesh_points = vec![];
+    let mut mesh_indices = vec![];
+    let mut vertex_indices: HashMap<ObjVertex, usize> = HashMap::new();
+    let mut vertices = vec![];
+    for face in faces {
+        let face_indices: Vec<usize> = face
+            .iter()
+            .map(|vertex| {
+                *vertex_indices.entry(*vertex).or_insert_with(|| {
+                    let [x, y, z] = positions[vertex.0];
+                    mesh_points.push(Point::<3>::new([x.m(), y.m(), z.m()]));
+                    vertices.push(*vertex);
+                    mesh_points.len() - 1
+                })
+            })
+            .collect();
+        for i in 1..face_indices.len() - 1 {
+            mesh_indices.push([face_indices[0], face_indices[i], face_indices[i + 1]]);
+        }
+    }
+
+    let mesh_normals = match vertices
+        .iter()
+        .map(|vertex| vertex.2)
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(normal_indices) => normal_indices
+            .iter()
+            .map(|i| Dir::try_from(normals[*i]).ok())
+            .collect::<Option<Vec<_>>>()?,
+        None => vec![],
+    };
+    let mesh_uvs = match vertices
+        .iter()
+        .map(|vertex| vertex.1)
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(uv_indices) => uv_indices.iter().map(|i| uvs[*i]).collect(),
+        None => vec![],
+    };
+
+    Some(IndexedMesh {
+        points: mesh_points,
+        indices: mesh_indices,
+        normals: mesh_normals,
+        uvs: mesh_uvs,
+    })
+}
+
+fn parse_obj_floats<'a, const N: usize>(
+    mut tokens: impl Iterator<Item = &'a str>,
+) -> Option<[f64; N]> {
+    let mut values = [0.; N];
+    for value in &mut values {
+        *value = tokens.next()?.parse().ok()?;
+    }
+    Some(values)
+}
+
+/// Parse a face vertex in one of the formats `v`, `v/vt`, `v//vn` or `v/vt/vn`.
+fn parse_obj_vertex(
+    token: &str,
+    n_positions: usize,
+    n_uvs: usize,
+    n_normals: usize,
+) -> Option<ObjVertex> {
+    let mut parts = token.split('/');
+    let position = resolve_obj_index(parts.next()?, n_positions)?;
+    let uv = match parts.next() {
+        Some("") | None => None,
+        Some(index) => Some(resolve_obj_index(index, n_uvs)?),
+    };
+    let normal = match parts.next() {
+        Some("") | None => None,
+        Some(index) => Some(resolve_obj_index(index, n_normals)?),
+    };
+    Some((position, uv, normal))
+}
+
+/// Convert a one-based or negative relative OBJ index into a zero-based index.
+fn resolve_obj_index(index: &str, len: usize) -> Option<usize> {
+    let index: i64 = index.parse().ok()?;
+    let resolved = if index < 0 {
+        len as i64 + index
+    } else {
+        index - 1
+    };
+    if resolved < 0 || resolved >= len as i64 {
+        return None;
+    }
+    Some(resolved as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cuboid, dir, point};
+
+    const ASCII_TETRAHEDRON: &str = "solid tetrahedron
+  facet normal 0 0 -1
+    outer loop
+      vertex 0 0 0
+      vertex 0 1 0
+      vertex 1 0 0
+    endloop
+  endfacet
+  facet normal 0 -1 0
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 0 0 1
+    endloop
+  endfacet
+  facet normal -1 0 0
+    outer loop
+      vertex 0 0 0
+      vertex 0 0 1
+      vertex 0 1 0
+    endloop
+  endfacet
+  facet normal 1 1 1
+    outer loop
+      vertex 1 0 0
+      vertex 0 1 0
+      vertex 0 0 1
+    endloop
+  endfacet
+endsolid tetrahedron
+";
+
+    fn binary_stl(triangles: &[[[f32; 3]; 3]]) -> Vec<u8> {
+        let mut bytes = vec![0; 80];
+        bytes.extend((triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            bytes.extend([0_u8; 12]);
+            for vertex in triangle {
+                for coordinate in vertex {
+                    bytes.extend(coordinate.to_le_bytes());
+                }
+            }
+            bytes.extend([0_u8; 2]);
+        }
+        bytes
+    }
+
+    fn write_temp_file(content: &[u8]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().expect("could not create tempfile");
+        file.write_all(content).expect("could not write tempfile");
+        file
+    }
+
+    #[test]
+    fn read_ascii_stl() {
+        let file = write_temp_file(ASCII_TETRAHEDRON.as_bytes());
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        assert_eq!(mesh.points().len(), 4);
+        assert_eq!(mesh.indices().len(), 4);
+        assert_eq!(mesh.points()[0], point!(0, 0, 0));
+        assert_eq!(mesh.indices()[0], [0, 1, 2]);
+        assert_eq!(mesh.indices()[3], [2, 1, 3]);
+        assert!(mesh.normals().is_empty());
+    }
+
+    #[test]
+    fn read_binary_stl() {
+        let file = write_temp_file(&binary_stl(&[
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+        ]));
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        assert_eq!(mesh.points().len(), 4);
+        assert_eq!(mesh.indices(), &vec![[0, 1, 2], [1, 3, 2]]);
+    }
+
+    #[test]
+    fn read_invalid_stl() {
+        let file = write_temp_file(b"not an stl file");
+        assert_eq!(
+            IndexedMesh::read_stl(file.path()),
+            Err(Error::StlRead(file.path().into()))
+        );
+    }
+
+    #[test]
+    fn read_missing_stl() {
+        assert_eq!(
+            IndexedMesh::read_stl("does/not/exist.stl"),
+            Err(Error::StlRead("does/not/exist.stl".into()))
+        );
+    }
+
+    const OBJ_CUBE: &str = "# unit cube
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+v 0 0 1
+v 1 0 1
+v 1 1 1
+v 0 1 1
+f 1 4 3 2
+f 5 6 7 8
+f 1 2 6 5
+f 2 3 7 6
+f 3 4 8 7
+f 4 1 5 8
+";
+
+    #[test]
+    fn read_obj_cube() {
+        let file = write_temp_file(OBJ_CUBE.as_bytes());
+        let mesh = IndexedMesh::read_obj(file.path()).unwrap();
+        assert_eq!(mesh.points().len(), 8);
+        assert_eq!(mesh.indices().len(), 12);
+        assert_eq!(mesh.indices()[0], [0, 1, 2]);
+        assert_eq!(mesh.indices()[1], [0, 2, 3]);
+        assert!(mesh.normals().is_empty());
+        assert!(mesh.uvs().is_empty());
+    }
+
+    #[test]
+    fn read_obj_face_formats() {
+        let obj = "v 0 0 0
+v 1 0 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 0 1
+vn 0 0 2
+f 1/1/1 2/2/1 3/3/1
+f 1/1 2/2 3/3
+f 1//1 2//1 3//1
+f -3 -2 -1
+";
+        let file = write_temp_file(obj.as_bytes());
+        let mesh = IndexedMesh::read_obj(file.path()).unwrap();
+        assert_eq!(mesh.indices().len(), 4);
+        assert_eq!(mesh.points().len(), 12);
+        assert!(mesh.normals().is_empty());
+        assert!(mesh.uvs().is_empty());
+    }
+
+    #[test]
+    fn read_obj_normals_and_uvs() {
+        let obj = "v 0 0 0
+v 1 0 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 0 1
+vn 0 0 2
+f 1/1/1 2/2/1 3/3/1
+";
+        let file = write_temp_file(obj.as_bytes());
+        let mesh = IndexedMesh::read_obj(file.path()).unwrap();
+        assert_eq!(mesh.normals(), &vec![dir!(0, 0, 1); 3]);
+        assert_eq!(mesh.uvs(), &vec![[0., 0.], [1., 0.], [0., 1.]]);
+    }
+
+    #[test]
+    fn read_obj_invalid_index() {
+        let file = write_temp_file(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n");
+        assert_eq!(
+            IndexedMesh::read_obj(file.path()),
+            Err(Error::ObjRead(file.path().into()))
+        );
+    }
+
+    #[test]
+    fn read_stl_written_by_part() {
+        let file = NamedTempFile::new().expect("could not create tempfile");
+        Cuboid::from_dim(1.m(), 2.m(), 3.m())
+            .write_stl(file.path())
+            .unwrap();
+
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        assert_eq!(mesh.points().len(), 8);
+        assert_eq!(mesh.indices().len(), 12);
+    }
+
+    #[test]
+    fn bounding_box_of_cuboid() {
+        let mesh = Cuboid::from_dim(1.mm(), 2.mm(), 3.mm())
+            .triangulate()
+            .unwrap();
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert_relative_eq!(min, point!(-0.5.mm(), -1.mm(), -1.5.mm()));
+        assert_relative_eq!(max, point!(0.5.mm(), 1.mm(), 1.5.mm()));
+    }
+
+    #[test]
+    fn bounding_box_of_tetrahedron() {
+        let file = write_temp_file(ASCII_TETRAHEDRON.as_bytes());
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        assert_eq!(
+            mesh.bounding_box(),
+            Some((point!(0, 0, 0), point!(1.m(), 1.m(), 1.m())))
+        );
+        assert_relative_eq!(mesh.center(), point!(0.25.m(), 0.25.m(), 0.25.m()));
+    }
+
+    #[test]
+    fn bounding_box_of_empty_mesh() {
+        let mesh = IndexedMesh::from_triangles(&[]);
+        assert_eq!(mesh.bounding_box(), None);
+        assert_eq!(mesh.center(), Point::<3>::origin());
+    }
+
+    #[test]
+    fn render_mesh_round_trip() {
+        let render_mesh = RenderMesh::try_from(Cuboid::from_dim(1.m(), 2.m(), 3.m())).unwrap();
+        let indexed_mesh = IndexedMesh::from(render_mesh.clone());
+        assert_eq!(indexed_mesh.points().len(), render_mesh.points().len());
+        assert_eq!(indexed_mesh.indices().len(), render_mesh.indices().len());
+        assert_eq!(RenderMesh::try_from(indexed_mesh), Ok(render_mesh));
+    }
+
+    #[test]
+    fn render_mesh_from_mesh_without_normals() {
+        let file = write_temp_file(ASCII_TETRAHEDRON.as_bytes());
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        let render_mesh = RenderMesh::try_from(mesh.clone()).unwrap();
+        assert_eq!(render_mesh.points(), mesh.points());
+        assert_eq!(render_mesh.indices(), mesh.indices());
+        assert_eq!(render_mesh.normals().len(), 4);
+        assert_relative_eq!(render_mesh.normals()[0], dir!(-1, -1, -1));
+    }
+
+    #[test]
+    fn render_mesh_from_degenerate_mesh() {
+        let file = write_temp_file(&binary_stl(&[[[0., 0., 0.], [1., 0., 0.], [2., 0., 0.]]]));
+        let mesh = IndexedMesh::read_stl(file.path()).unwrap();
+        assert_eq!(RenderMesh::try_from(mesh), Err(Error::ZeroVector));
+    }
+}
@@ -0,0 +1,75 @@
+use uom::lib::marker::PhantomData;
+
+use crate::{Angle, Length};
+
+/// The parameters controlling how `Face`s and `Part`s are triangulated.
+///
+/// ```rust
+/// use anvil::{IntoAngle, IntoLength, MeshOptions};
+///
+/// let options = MeshOptions::default()
+///     .with_tolerance(1.mm())
+///     .with_angular_tolerance(10.deg());
+/// assert_eq!(options.tolerance, 1.mm());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshOptions {
+    /// The maximum distance between the mesh and the real surface.
+    pub tolerance: Length,
+    /// The maximum angle between the normals of adjacent triangles.
+    ///
+    /// This mainly affects the quality of small curved surfaces, which already satisfy the linear
+    /// tolerance with very few triangles.
+    pub angular_tolerance: Angle,
+}
+impl MeshOptions {
+    /// The linear tolerance used when none is specified.
+    pub const DEFAULT_TOLERANCE: Length = Length {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 0.000001,
+    };
+    /// The angular tolerance used when none is specified.
+    pub const DEFAULT_ANGULAR_TOLERANCE: Angle = Angle {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 0.5,
+    };
+    /// Coarse options for operations that only need the rough shape of a `Part`, e.g. sections.
+    ///
+    /// The angular tolerance keeps curved surfaces of small `Part`s round, while the linear
+    /// tolerance bounds the number of triangles of large ones.
+    pub(crate) const COARSE: Self = Self {
+        tolerance: Length {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: 0.001,
+        },
+        angular_tolerance: Angle {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: 5. * core::f64::consts::PI / 180.,
+        },
+    };
+
+    /// Return a copy of these `MeshOptions` with a different linear tolerance.
+    pub fn with_tolerance(self, tolerance: Length) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Return a copy of these `MeshOptions` with a different angular tolerance.
+    pub fn with_angular_tolerance(self, angular_tolerance: Angle) -> Self {
+        Self {
+            angular_tolerance,
+            ..self
+        }
+    }
+}
+impl Default for MeshOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: Self::DEFAULT_TOLERANCE,
+            angular_tolerance: Self::DEFAULT_ANGULAR_TOLERANCE,
+        }
+    }
+}
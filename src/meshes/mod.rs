@@ -1,3 +1,13 @@
+#[cfg(feature = "bevy")]
+mod bevy;
+mod indexed_mesh;
+mod mesh_options;
 mod render_mesh;
+mod uv_mode;
+mod vertex;
 
+pub use indexed_mesh::IndexedMesh;
+pub use mesh_options::MeshOptions;
 pub use render_mesh::RenderMesh;
+pub use uv_mode::UvMode;
+pub use vertex::Vertex;
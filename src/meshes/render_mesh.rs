@@ -1,15 +1,19 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
 use opencascade_sys::ffi;
-use uom::lib::marker::PhantomData;
+use uom::si::angle::radian;
 use uom::si::length::meter;
 
-use crate::{Dir, Error, Face, IntoLength, Length, Part, Point};
-
-const DEFAULT_TOLERANCE: Length = Length {
-    dimension: PhantomData,
-    units: PhantomData,
-    value: 0.000001,
+use crate::{
+    Angle, Dir, Error, Face, IndexedMesh, IntoLength, Length, MeshOptions, Part, Point, UvMode,
+    Vertex,
 };
 
+/// The number of times the linear deflection of a `Face` is tightened to reach an angular
+/// tolerance.
+const MAX_REFINEMENTS: usize = 4;
+
 /// A triangular mesh of one or more `Face`s optimized for 3D rendering.
 #[derive(Clone, Debug, PartialEq)]
 pub struct RenderMesh {
@@ -40,6 +44,31 @@ impl RenderMesh {
         }
     }
 
+    /// Triangulate a `Face` with a specified tolerance and texture coordinate mode.
+    ///
+    /// With `UvMode::Face`, this is equivalent to `RenderMesh::try_from((face, tolerance))`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle, RenderMesh, UvMode, point};
+    ///
+    /// let face = Rectangle::from_corners(point!(0, 0), point!(4.m(), 2.m()))
+    ///     .to_face(Plane::xy())
+    ///     .unwrap();
+    /// let mesh = RenderMesh::try_from_with_uv(face, 0.001.m(), UvMode::Planar(Plane::xy())).unwrap();
+    /// assert!(mesh.uvs().contains(&[4., 2.]));
+    /// ```
+    pub fn try_from_with_uv(face: Face, tolerance: Length, mode: UvMode) -> Result<Self, Error> {
+        let mut mesh = Self::try_from((face, tolerance))?;
+        if mode != UvMode::Face {
+            mesh.uvs = mesh
+                .points
+                .iter()
+                .map(|point| mode.uv(*point).expect("mode should not be per face"))
+                .collect();
+        }
+        Ok(mesh)
+    }
+
     /// Return the `Point`s of this `RenderMesh`.
     pub fn points(&self) -> &Vec<Point<3>> {
         &self.points
@@ -102,8 +131,33 @@ impl RenderMesh {
         }
         total_area
     }
+    /// Return the volume enclosed by the triangles of this `RenderMesh` in cubic meters.
+    ///
+    /// The volume is computed as the sum of the signed volumes of the tetrahedra spanned by the
+    /// origin and every triangle. The result is only meaningful for watertight meshes with
+    /// consistently outward oriented triangles, as produced from a closed `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mesh = RenderMesh::try_from(Cube::from_size(2.m())).unwrap();
+    /// assert_relative_eq!(mesh.volume(), 8.);
+    /// ```
+    pub fn volume(&self) -> f64 {
+        self.indices
+            .iter()
+            .map(|triangle| {
+                let [p1, p2, p3] =
+                    triangle.map(|i| self.points[i].0.map(|length| length.get::<meter>()));
+                dot(p1, triangle_cross([[0.; 3], p2, p3])) / 6.
+            })
+            .sum()
+    }
     /// Return the center point of the `RenderMesh`, i.e. the average of all mesh points.
     ///
+    /// If the `RenderMesh` does not contain any points, the origin is returned.
+    ///
     /// ```rust
     /// use anvil::{IntoLength, Plane, Rectangle, RenderMesh, point};
     /// use approx::assert_relative_eq;
@@ -117,11 +171,289 @@ impl RenderMesh {
     /// );
     /// ```
     pub fn center(&self) -> Point<3> {
-        let mut sum_of_points = Point::<3>::origin();
-        for point in &self.points {
-            sum_of_points = sum_of_points + *point;
+        Point::centroid(&self.points).unwrap_or(Point::<3>::origin())
+    }
+
+    /// Return a clone of this `RenderMesh` with the orientation of every triangle reversed.
+    ///
+    /// The winding order of all triangles is swapped and all normals are negated.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    ///
+    /// let mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// assert_eq!(mesh.flip_winding().flip_winding(), mesh);
+    /// ```
+    pub fn flip_winding(&self) -> Self {
+        Self {
+            points: self.points.clone(),
+            indices: self.indices.iter().map(|[a, b, c]| [*a, *c, *b]).collect(),
+            normals: self.normals.iter().map(|normal| -*normal).collect(),
+            uvs: self.uvs.clone(),
+        }
+    }
+    /// Return a clone of this `RenderMesh` with normals derived from the triangle geometry.
+    ///
+    /// The normal of every point is the area-weighted average of the normals of all triangles
+    /// using that point. Points that are not part of any triangle keep their previous normal.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    ///
+    /// let mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// assert_eq!(mesh.recomputed_normals().normals().len(), mesh.points().len());
+    /// ```
+    pub fn recomputed_normals(&self) -> Self {
+        let mut sums = vec![[0.; 3]; self.points.len()];
+        for triangle in &self.indices {
+            let normal = self.triangle_normal(*triangle);
+            for index in triangle {
+                for axis in 0..3 {
+                    sums[*index][axis] += normal[axis];
+                }
+            }
+        }
+        self.with_normal_sums(&sums)
+    }
+
+    /// Return a clone of this `RenderMesh` with normals smoothed across neighboring triangles.
+    ///
+    /// The normal of every point is the area-weighted average of the normals of all triangles
+    /// touching its position, including triangles of other faces. Triangles whose normal deviates
+    /// from the normal of the point by more than the crease angle are skipped, which preserves
+    /// hard edges.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoAngle, IntoLength, RenderMesh};
+    ///
+    /// let mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// let smooth = mesh.with_smooth_normals(30.deg());
+    /// assert_eq!(smooth.normals().len(), mesh.normals().len());
+    /// ```
+    pub fn with_smooth_normals(&self, crease_angle: Angle) -> Self {
+        let triangle_normals: Vec<[f64; 3]> = self
+            .indices
+            .iter()
+            .map(|triangle| self.triangle_normal(*triangle))
+            .collect();
+
+        let mut triangles_at_point: HashMap<[u64; 3], Vec<usize>> = HashMap::new();
+        let mut own_sums = vec![[0.; 3]; self.points.len()];
+        for (triangle_index, triangle) in self.indices.iter().enumerate() {
+            for index in triangle {
+                triangles_at_point
+                    .entry(self.points[*index].hash_key())
+                    .or_default()
+                    .push(triangle_index);
+                for axis in 0..3 {
+                    own_sums[*index][axis] += triangle_normals[triangle_index][axis];
+                }
+            }
+        }
+
+        let min_cos = crease_angle.get::<radian>().cos();
+        let sums: Vec<[f64; 3]> = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let mut sum = [0.; 3];
+                let Ok(reference) = Dir::try_from(own_sums[i]) else {
+                    return sum;
+                };
+                for triangle_index in &triangles_at_point[&point.hash_key()] {
+                    let normal = triangle_normals[*triangle_index];
+                    let is_own = self.indices[*triangle_index].contains(&i);
+                    let is_smooth =
+                        Dir::try_from(normal).is_ok_and(|normal| normal.dot(reference) >= min_cos);
+                    if is_own || is_smooth {
+                        for axis in 0..3 {
+                            sum[axis] += normal[axis];
+                        }
+                    }
+                }
+                sum
+            })
+            .collect();
+        self.with_normal_sums(&sums)
+    }
+
+    /// Return a simplified clone of this `RenderMesh` with at most `target_triangles` triangles.
+    ///
+    /// Edges are collapsed in the order of their quadric error, so flat regions are simplified
+    /// before detailed ones. Collapses that would flip a triangle or move a boundary edge are
+    /// skipped, so the result may contain more triangles than requested if no valid collapse is
+    /// left.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, RenderMesh, Sphere};
+    ///
+    /// let mesh = RenderMesh::try_from((Sphere::from_radius(1.m()), 0.001.m())).unwrap();
+    /// let decimated = mesh.decimate(500);
+    /// assert!(decimated.indices().len() < mesh.indices().len());
+    /// ```
+    pub fn decimate(&self, target_triangles: usize) -> Self {
+        let mut positions: Vec<[f64; 3]> = self
+            .points
+            .iter()
+            .map(|point| point.0.map(|length| length.get::<meter>()))
+            .collect();
+        let mut normals = self.normals.clone();
+        let mut uvs = self.uvs.clone();
+        let mut triangles: Vec<Option<[usize; 3]>> =
+            self.indices.iter().copied().map(Some).collect();
+
+        let mut triangles_at_point = vec![vec![]; positions.len()];
+        let mut quadrics = vec![[[0.; 4]; 4]; positions.len()];
+        let mut edge_counts: HashMap<(usize, usize), usize> = HashMap::new();
+        for (triangle_index, triangle) in self.indices.iter().enumerate() {
+            let quadric = plane_quadric(&positions, *triangle);
+            for (corner, index) in triangle.iter().enumerate() {
+                triangles_at_point[*index].push(triangle_index);
+                quadrics[*index] = add_quadrics(quadrics[*index], quadric);
+                let next = triangle[(corner + 1) % 3];
+                *edge_counts.entry(sorted_edge(*index, next)).or_default() += 1;
+            }
+        }
+        let mut is_fixed = vec![false; positions.len()];
+        for ((a, b), count) in &edge_counts {
+            if *count != 2 {
+                is_fixed[*a] = true;
+                is_fixed[*b] = true;
+            }
+        }
+
+        let mut versions = vec![0_usize; positions.len()];
+        let mut queue = BinaryHeap::new();
+        for (a, b) in edge_counts.keys() {
+            queue.push(collapse_candidate(&positions, &quadrics, &versions, *a, *b));
+        }
+
+        let mut triangle_count = self.indices.len();
+        while triangle_count > target_triangles {
+            let Some(collapse) = queue.pop() else {
+                break;
+            };
+            let (a, b) = (collapse.keep, collapse.remove);
+            if versions[a] != collapse.versions.0
+                || versions[b] != collapse.versions.1
+                || is_fixed[a]
+                || is_fixed[b]
+                || !is_valid_collapse(&positions, &triangles, &triangles_at_point, &collapse)
+            {
+                continue;
+            }
+
+            for triangle_index in triangles_at_point[b].clone() {
+                let Some(triangle) = triangles[triangle_index].as_mut() else {
+                    continue;
+                };
+                if triangle.contains(&a) {
+                    triangles[triangle_index] = None;
+                    triangle_count -= 1;
+                } else {
+                    triangle
+                        .iter_mut()
+                        .filter(|i| **i == b)
+                        .for_each(|i| *i = a);
+                    triangles_at_point[a].push(triangle_index);
+                }
+            }
+            triangles_at_point[b].clear();
+            triangles_at_point[a].retain(|t| triangles[*t].is_some());
+
+            let weight = collapse.weight;
+            positions[a] = collapse.position;
+            if let (Some(normal_a), Some(normal_b)) = (normals.get(a), normals.get(b)) {
+                normals[a] = Dir::try_from(
+                    [0, 1, 2]
+                        .map(|axis| (1. - weight) * normal_a.0[axis] + weight * normal_b.0[axis]),
+                )
+                .unwrap_or(*normal_a);
+            }
+            if let (Some(uv_a), Some(uv_b)) = (uvs.get(a), uvs.get(b)) {
+                uvs[a] = [0, 1].map(|axis| (1. - weight) * uv_a[axis] + weight * uv_b[axis]);
+            }
+            quadrics[a] = add_quadrics(quadrics[a], quadrics[b]);
+            versions[a] += 1;
+            versions[b] += 1;
+
+            let mut neighbors: Vec<usize> = triangles_at_point[a]
+                .iter()
+                .filter_map(|t| triangles[*t])
+                .flatten()
+                .filter(|i| *i != a)
+                .collect();
+            neighbors.sort();
+            neighbors.dedup();
+            for neighbor in neighbors {
+                queue.push(collapse_candidate(
+                    &positions, &quadrics, &versions, a, neighbor,
+                ));
+            }
         }
-        sum_of_points / self.points.len() as f64
+
+        let triangles: Vec<[usize; 3]> = triangles.into_iter().flatten().collect();
+        let mut is_used = vec![false; positions.len()];
+        triangles
+            .iter()
+            .flatten()
+            .for_each(|index| is_used[*index] = true);
+
+        let mut mesh = Self::empty();
+        let mut index_map = vec![0; positions.len()];
+        for index in (0..positions.len()).filter(|index| is_used[*index]) {
+            index_map[index] = mesh.points.len();
+            mesh.points
+                .push(Point(positions[index].map(|coordinate| coordinate.m())));
+            mesh.normals.extend(normals.get(index));
+            mesh.uvs.extend(uvs.get(index));
+        }
+        mesh.indices = triangles
+            .into_iter()
+            .map(|triangle| triangle.map(|index| index_map[index]))
+            .collect();
+        mesh
+    }
+
+    /// Return the vertices and flattened triangle indices of this `RenderMesh` in a format
+    /// suitable for GPU vertex and index buffers.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    ///
+    /// let mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// let (vertices, indices) = mesh.to_vertex_buffer();
+    /// assert_eq!(vertices.len(), mesh.points().len());
+    /// assert_eq!(indices.len(), 3 * mesh.indices().len());
+    /// ```
+    pub fn to_vertex_buffer(&self) -> (Vec<Vertex>, Vec<u32>) {
+        let vertices = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| Vertex {
+                position: [
+                    point.x().get::<meter>() as f32,
+                    point.y().get::<meter>() as f32,
+                    point.z().get::<meter>() as f32,
+                ],
+                normal: self.normals.get(i).map_or([0.; 3], |normal| {
+                    [normal.x() as f32, normal.y() as f32, normal.z() as f32]
+                }),
+                uv: self
+                    .uvs
+                    .get(i)
+                    .map_or([0.; 2], |[u, v]| [*u as f32, *v as f32]),
+            })
+            .collect();
+        let indices = self
+            .indices
+            .iter()
+            .flatten()
+            .map(|index| *index as u32)
+            .collect();
+        (vertices, indices)
     }
 
     fn empty() -> Self {
@@ -133,32 +465,11 @@ impl RenderMesh {
         }
     }
 
-    fn merge_with(&mut self, other: Self) {
-        self.indices.extend(other.indices().iter().map(|t| {
-            [
-                t[0] + self.points.len(),
-                t[1] + self.points.len(),
-                t[2] + self.points.len(),
-            ]
-        }));
-        self.points.extend(other.points());
-        self.normals.extend(other.normals());
-        self.uvs.extend(other.uvs());
-    }
-}
-
-impl TryFrom<Face> for RenderMesh {
-    type Error = Error;
-    fn try_from(face: Face) -> Result<Self, Self::Error> {
-        (face, DEFAULT_TOLERANCE).try_into()
-    }
-}
-impl TryFrom<(Face, Length)> for RenderMesh {
-    type Error = Error;
-    fn try_from((face, tolerance): (Face, Length)) -> Result<Self, Self::Error> {
+    /// Triangulate a `Face` with a linear deflection in meters.
+    fn from_occt_face(face: &Face, deflection: f64) -> Result<Self, Error> {
         let mesh = ffi::BRepMesh_IncrementalMesh_ctor(
             ffi::cast_face_to_shape(face.0.as_ref().unwrap()),
-            tolerance.get::<meter>(),
+            deflection,
         );
         let face = ffi::TopoDS_cast_to_face(mesh.as_ref().unwrap().Shape());
         let mut location = ffi::TopLoc_Location_ctor();
@@ -243,30 +554,363 @@ impl TryFrom<(Face, Length)> for RenderMesh {
             Err(Error::Triangulation)
         }
     }
+
+    /// Return the largest angle in radians between the normals of two triangles that would meet
+    /// at a `Point` of this `RenderMesh`.
+    ///
+    /// This is estimated as twice the largest angle between a triangle and the surface normals at
+    /// its corners.
+    fn max_normal_angle(&self) -> f64 {
+        self.indices
+            .iter()
+            .filter_map(|triangle| {
+                let normal = Dir::<3>::try_from(self.triangle_normal(*triangle)).ok()?;
+                triangle
+                    .iter()
+                    .map(|index| 2. * normal.dot(self.normals[*index]).clamp(-1., 1.).acos())
+                    .reduce(f64::max)
+            })
+            .fold(0., f64::max)
+    }
+
+    /// Return the non-normalized normal vector of a triangle, whose length is twice its area.
+    fn triangle_normal(&self, [a, b, c]: [usize; 3]) -> [f64; 3] {
+        let edge_1 = self.points[b] - self.points[a];
+        let edge_2 = self.points[c] - self.points[a];
+        let [x1, y1, z1] = edge_1.0.map(|length| length.get::<meter>());
+        let [x2, y2, z2] = edge_2.0.map(|length| length.get::<meter>());
+        [y1 * z2 - z1 * y2, z1 * x2 - x1 * z2, x1 * y2 - y1 * x2]
+    }
+
+    /// Return a clone of this `RenderMesh` with every normal replaced by its normalized sum.
+    ///
+    /// Sums of zero length keep the previous normal of their point.
+    fn with_normal_sums(&self, sums: &[[f64; 3]]) -> Self {
+        Self {
+            points: self.points.clone(),
+            indices: self.indices.clone(),
+            normals: sums
+                .iter()
+                .enumerate()
+                .map(|(i, sum)| {
+                    Dir::try_from(*sum)
+                        .ok()
+                        .or(self.normals.get(i).copied())
+                        .unwrap_or(Dir([0., 0., 1.]))
+                })
+                .collect(),
+            uvs: self.uvs.clone(),
+        }
+    }
+
+    /// Combine multiple `RenderMesh`es into a single one.
+    ///
+    /// The triangle indices of every mesh are offset so they keep referencing their own points.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    ///
+    /// let mesh1 = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// let mesh2 = RenderMesh::try_from(Cube::from_size(2.m())).unwrap();
+    /// let merged = RenderMesh::merge(vec![mesh1.clone(), mesh2.clone()]);
+    /// assert_eq!(merged.points().len(), mesh1.points().len() + mesh2.points().len());
+    /// assert_eq!(merged.indices().len(), mesh1.indices().len() + mesh2.indices().len());
+    /// ```
+    pub fn merge(meshes: Vec<Self>) -> Self {
+        let mut merged_mesh = Self::empty();
+        for mesh in meshes {
+            merged_mesh.append(mesh);
+        }
+        merged_mesh
+    }
+    /// Add the points and triangles of another `RenderMesh` to this one.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, RenderMesh};
+    ///
+    /// let mut mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+    /// mesh.append(RenderMesh::try_from(Cube::from_size(2.m())).unwrap());
+    /// assert_eq!(mesh.indices().len(), 24);
+    /// ```
+    pub fn append(&mut self, other: Self) {
+        let offset = self.points.len();
+        self.indices.extend(
+            other
+                .indices
+                .iter()
+                .map(|t| [t[0] + offset, t[1] + offset, t[2] + offset]),
+        );
+        self.points.extend(other.points);
+        self.normals.extend(other.normals);
+        self.uvs.extend(other.uvs);
+    }
+}
+
+impl TryFrom<Face> for RenderMesh {
+    type Error = Error;
+    fn try_from(face: Face) -> Result<Self, Self::Error> {
+        (face, MeshOptions::DEFAULT_TOLERANCE).try_into()
+    }
+}
+impl TryFrom<(Face, Length)> for RenderMesh {
+    type Error = Error;
+    fn try_from((face, tolerance): (Face, Length)) -> Result<Self, Self::Error> {
+        (face, tolerance, MeshOptions::DEFAULT_ANGULAR_TOLERANCE).try_into()
+    }
 }
+impl TryFrom<(Face, Length, Angle)> for RenderMesh {
+    type Error = Error;
+    /// Triangulate a `Face` with a linear and an angular tolerance.
+    ///
+    /// The linear tolerance limits the distance between the mesh and the real surface, the
+    /// angular tolerance limits the angle between the normals of adjacent triangles. OCCT never
+    /// exceeds an angle of 0.5 rad, so larger angular tolerances have no effect.
+    fn try_from(
+        (face, tolerance, angular_tolerance): (Face, Length, Angle),
+    ) -> Result<Self, Self::Error> {
+        // OCCT is only given the linear deflection, so it is tightened until the triangles follow
+        // the surface within the angular tolerance
+        let angular_tolerance = angular_tolerance.get::<radian>();
+        let mut deflection = tolerance.get::<meter>();
+        let mut mesh = Self::from_occt_face(&face, deflection)?;
+        for _ in 0..MAX_REFINEMENTS {
+            let angle = mesh.max_normal_angle();
+            if angle <= angular_tolerance {
+                break;
+            }
+            // the distance between an arc and its chord grows with the square of the angle
+            deflection *= (0.9 * angular_tolerance / angle).powi(2).max(0.01);
+            let refined = Self::from_occt_face(&face, deflection)?;
+            if refined.max_normal_angle() >= angle {
+                break;
+            }
+            mesh = refined;
+        }
+        Ok(mesh)
+    }
+}
+
 impl TryFrom<Part> for RenderMesh {
     type Error = Error;
     fn try_from(part: Part) -> Result<Self, Self::Error> {
-        (part, DEFAULT_TOLERANCE).try_into()
+        (part, MeshOptions::DEFAULT_TOLERANCE).try_into()
     }
 }
 impl TryFrom<(Part, Length)> for RenderMesh {
     type Error = Error;
     fn try_from((part, tolerance): (Part, Length)) -> Result<Self, Self::Error> {
+        (part, tolerance, MeshOptions::DEFAULT_ANGULAR_TOLERANCE).try_into()
+    }
+}
+impl TryFrom<(Part, Length, Angle)> for RenderMesh {
+    type Error = Error;
+    /// Triangulate all `Face`s of a `Part` with a linear and an angular tolerance.
+    fn try_from(
+        (part, tolerance, angular_tolerance): (Part, Length, Angle),
+    ) -> Result<Self, Self::Error> {
         let meshes = part
             .faces()
-            .map(|face| RenderMesh::try_from((face, tolerance)))
+            .map(|face| RenderMesh::try_from((face, tolerance, angular_tolerance)))
             .collect::<Result<Vec<RenderMesh>, Error>>()?;
-        Ok(merge(meshes))
+        Ok(RenderMesh::merge(meshes))
+    }
+}
+impl TryFrom<IndexedMesh> for RenderMesh {
+    type Error = Error;
+    /// Convert an `IndexedMesh` into a `RenderMesh` without duplicating any `Point`s.
+    ///
+    /// If the `IndexedMesh` has no normals, they are derived from the triangle geometry like in
+    /// `RenderMesh::recomputed_normals()`. This fails with `Error::ZeroVector` if a `Point` only
+    /// belongs to triangles without area, as no normal can be derived for it.
+    fn try_from(mesh: IndexedMesh) -> Result<Self, Self::Error> {
+        let render_mesh = Self {
+            points: mesh.points().clone(),
+            indices: mesh.indices().clone(),
+            normals: mesh.normals().clone(),
+            uvs: mesh.uvs().clone(),
+        };
+        if !render_mesh.normals.is_empty() {
+            return Ok(render_mesh);
+        }
+
+        let mut sums = vec![[0.; 3]; render_mesh.points.len()];
+        for triangle in &render_mesh.indices {
+            let normal = render_mesh.triangle_normal(*triangle);
+            for index in triangle {
+                for axis in 0..3 {
+                    sums[*index][axis] += normal[axis];
+                }
+            }
+        }
+        let normals = sums
+            .into_iter()
+            .map(Dir::<3>::try_from)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self {
+            normals,
+            ..render_mesh
+        })
+    }
+}
+
+/// A potential contraction of the edge between two points of a `RenderMesh` into a single point.
+struct Collapse {
+    cost: f64,
+    keep: usize,
+    remove: usize,
+    /// The new position of the kept point.
+    position: [f64; 3],
+    /// The relative distance of the new position from the kept to the removed point.
+    weight: f64,
+    /// The versions of both points at the time this candidate was computed.
+    versions: (usize, usize),
+}
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Collapse {}
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Collapse {
+    /// Order collapses by descending cost so the cheapest is popped first from a `BinaryHeap`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// Find the cheapest of the two end points and the midpoint of an edge to collapse it into.
+fn collapse_candidate(
+    positions: &[[f64; 3]],
+    quadrics: &[[[f64; 4]; 4]],
+    versions: &[usize],
+    a: usize,
+    b: usize,
+) -> Collapse {
+    let quadric = add_quadrics(quadrics[a], quadrics[b]);
+    let (weight, position, cost) = [0., 0.5, 1.]
+        .into_iter()
+        .map(|weight| {
+            let position = [0, 1, 2]
+                .map(|axis| (1. - weight) * positions[a][axis] + weight * positions[b][axis]);
+            (weight, position, quadric_error(quadric, position))
+        })
+        .min_by(|(_, _, cost1), (_, _, cost2)| cost1.total_cmp(cost2))
+        .expect("candidates should not be empty");
+    Collapse {
+        cost,
+        keep: a,
+        remove: b,
+        position,
+        weight,
+        versions: (versions[a], versions[b]),
     }
 }
 
-fn merge(meshes: Vec<RenderMesh>) -> RenderMesh {
-    let mut merged_mesh = RenderMesh::empty();
-    for mesh in meshes {
-        merged_mesh.merge_with(mesh);
+/// Check that a collapse keeps the mesh manifold and does not flip or degenerate any triangle.
+fn is_valid_collapse(
+    positions: &[[f64; 3]],
+    triangles: &[Option<[usize; 3]>],
+    triangles_at_point: &[Vec<usize>],
+    collapse: &Collapse,
+) -> bool {
+    let (a, b) = (collapse.keep, collapse.remove);
+    let neighbors = |point: usize| {
+        let mut neighbors: Vec<usize> = triangles_at_point[point]
+            .iter()
+            .filter_map(|t| triangles[*t])
+            .flatten()
+            .filter(|i| *i != point)
+            .collect();
+        neighbors.sort();
+        neighbors.dedup();
+        neighbors
+    };
+    let neighbors_a = neighbors(a);
+    let shared_neighbors = neighbors(b)
+        .into_iter()
+        .filter(|i| neighbors_a.contains(i))
+        .count();
+    let shared_triangles = triangles_at_point[a]
+        .iter()
+        .filter_map(|t| triangles[*t])
+        .filter(|triangle| triangle.contains(&b))
+        .count();
+    if shared_neighbors != shared_triangles {
+        return false;
     }
-    merged_mesh
+
+    [a, b].into_iter().all(|point| {
+        triangles_at_point[point]
+            .iter()
+            .filter_map(|t| triangles[*t])
+            .filter(|triangle| !(triangle.contains(&a) && triangle.contains(&b)))
+            .all(|triangle| {
+                let corners = triangle.map(|i| positions[i]);
+                let moved = triangle.map(|i| {
+                    if i == point {
+                        collapse.position
+                    } else {
+                        positions[i]
+                    }
+                });
+                let old_normal = triangle_cross(corners);
+                let new_normal = triangle_cross(moved);
+                let new_length = dot(new_normal, new_normal).sqrt();
+                new_length > 1e-12 * dot(old_normal, old_normal).sqrt()
+                    && dot(old_normal, new_normal) > 0.
+            })
+    })
+}
+
+/// Return the error quadric of the plane spanned by a triangle.
+fn plane_quadric(positions: &[[f64; 3]], triangle: [usize; 3]) -> [[f64; 4]; 4] {
+    let corners = triangle.map(|i| positions[i]);
+    let normal = triangle_cross(corners);
+    let length = dot(normal, normal).sqrt();
+    if length == 0. {
+        return [[0.; 4]; 4];
+    }
+    let [a, b, c] = normal.map(|component| component / length);
+    let plane = [a, b, c, -dot([a, b, c], corners[0])];
+    plane.map(|row| plane.map(|column| row * column))
+}
+
+fn add_quadrics(a: [[f64; 4]; 4], b: [[f64; 4]; 4]) -> [[f64; 4]; 4] {
+    [0, 1, 2, 3].map(|row| [0, 1, 2, 3].map(|column| a[row][column] + b[row][column]))
+}
+
+fn quadric_error(quadric: [[f64; 4]; 4], [x, y, z]: [f64; 3]) -> f64 {
+    let vector = [x, y, z, 1.];
+    (0..4)
+        .map(|row| {
+            (0..4)
+                .map(|column| vector[row] * quadric[row][column] * vector[column])
+                .sum::<f64>()
+        })
+        .sum()
+}
+
+fn triangle_cross([p1, p2, p3]: [[f64; 3]; 3]) -> [f64; 3] {
+    let u = [0, 1, 2].map(|axis| p2[axis] - p1[axis]);
+    let v = [0, 1, 2].map(|axis| p3[axis] - p1[axis]);
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn sorted_edge(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
 }
 
 #[cfg(test)]
@@ -275,10 +919,305 @@ mod tests {
 
     use approx::{assert_abs_diff_eq, assert_relative_eq};
 
-    use crate::{Axis, Circle, Cube, IntoAngle, IntoLength, Path, Plane, Rectangle, dir, point};
+    use crate::{
+        Axis, Circle, Cube, IntoAngle, IntoLength, Path, Plane, Rectangle, Sphere, dir, point,
+    };
 
     use super::*;
 
+    #[test]
+    fn to_vertex_buffer() {
+        let mesh = RenderMesh {
+            points: vec![
+                point!(0, 0, 0),
+                point!(1.m(), 0.m(), 0.m()),
+                point!(1.m(), 1.m(), 0.m()),
+                point!(0.m(), 2.m(), 0.m()),
+            ],
+            indices: vec![[0, 1, 2], [0, 2, 3]],
+            normals: vec![dir!(0, 0, 1); 4],
+            uvs: vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+        };
+        let (vertices, indices) = mesh.to_vertex_buffer();
+
+        assert_eq!(vertices.len(), mesh.points().len());
+        assert_eq!(indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(
+            vertices[3],
+            Vertex {
+                position: [0., 2., 0.],
+                normal: [0., 0., 1.],
+                uv: [0., 1.],
+            }
+        );
+    }
+
+    #[test]
+    fn to_vertex_buffer_empty() {
+        let (vertices, indices) = RenderMesh::empty().to_vertex_buffer();
+        assert!(vertices.is_empty());
+        assert!(indices.is_empty());
+    }
+
+    fn tetrahedron() -> RenderMesh {
+        RenderMesh {
+            points: vec![
+                point!(0, 0, 0),
+                point!(1.m(), 0.m(), 0.m()),
+                point!(0.m(), 1.m(), 0.m()),
+                point!(0.m(), 0.m(), 1.m()),
+            ],
+            indices: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+            normals: vec![dir!(0, 0, 1); 4],
+            uvs: vec![[0., 0.]; 4],
+        }
+    }
+
+    #[test]
+    fn flip_winding() {
+        let mesh = tetrahedron();
+        let flipped = mesh.flip_winding();
+        assert_eq!(flipped.indices()[0], [0, 1, 2]);
+        assert_eq!(flipped.normals()[0], dir!(0, 0, -1));
+        assert_eq!(flipped.flip_winding(), mesh);
+    }
+
+    #[test]
+    fn recomputed_normals_tetrahedron() {
+        let normals = tetrahedron().recomputed_normals().normals().clone();
+        assert_relative_eq!(normals[0], dir!(-1, -1, -1));
+        assert_relative_eq!(normals[3], dir!(0, 0, 1));
+    }
+
+    #[test]
+    fn recomputed_normals_cube() {
+        let mesh = RenderMesh::try_from(Cube::from_size(2.m())).unwrap();
+        let recomputed = mesh.recomputed_normals();
+        for (point, normal) in recomputed.points().iter().zip(recomputed.normals()) {
+            let outward = Dir::try_from(point.0.map(|length| length.get::<meter>())).unwrap();
+            assert!(normal.dot(outward) > 0.);
+        }
+        for (normal, expected) in recomputed.normals().iter().zip(mesh.normals()) {
+            assert_relative_eq!(normal, expected);
+        }
+    }
+
+    fn folded_square() -> RenderMesh {
+        RenderMesh {
+            points: vec![
+                point!(0, 0, 0),
+                point!(1.m(), 0.m(), 0.m()),
+                point!(0.m(), 1.m(), 0.m()),
+                point!(0, 0, 0),
+                point!(1.m(), 0.m(), 0.m()),
+                point!(0.m(), 0.m(), 1.m()),
+            ],
+            indices: vec![[0, 1, 2], [3, 5, 4]],
+            normals: vec![
+                dir!(0, 0, 1),
+                dir!(0, 0, 1),
+                dir!(0, 0, 1),
+                dir!(0, 1, 0),
+                dir!(0, 1, 0),
+                dir!(0, 1, 0),
+            ],
+            uvs: vec![[0., 0.]; 6],
+        }
+    }
+
+    #[test]
+    fn with_smooth_normals_keeps_creases() {
+        let mesh = folded_square();
+        assert_eq!(mesh.with_smooth_normals(30.deg()), mesh);
+    }
+
+    #[test]
+    fn with_smooth_normals_smooths_below_crease_angle() {
+        let smooth = folded_square().with_smooth_normals(100.deg());
+        assert_relative_eq!(smooth.normals()[0], dir!(0, 1, 1));
+        assert_relative_eq!(smooth.normals()[4], dir!(0, 1, 1));
+        assert_relative_eq!(smooth.normals()[2], dir!(0, 0, 1));
+        assert_relative_eq!(smooth.normals()[5], dir!(0, 1, 0));
+    }
+
+    #[test]
+    fn max_normal_angle() {
+        assert_relative_eq!(folded_square().max_normal_angle(), 0.);
+        let smooth = folded_square().with_smooth_normals(100.deg());
+        assert_relative_eq!(smooth.max_normal_angle(), f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn with_smooth_normals_cube() {
+        let mesh = RenderMesh::try_from(Cube::from_size(2.m())).unwrap();
+        let smooth = mesh.with_smooth_normals(30.deg());
+        for (normal, expected) in smooth.normals().iter().zip(mesh.normals()) {
+            assert_relative_eq!(normal, expected);
+        }
+    }
+
+    #[test]
+    fn with_smooth_normals_sphere() {
+        let mesh = RenderMesh::try_from((Sphere::from_radius(1.m()), 0.01.m())).unwrap();
+        let smooth = mesh.with_smooth_normals(30.deg());
+        for (point, normal) in smooth.points().iter().zip(smooth.normals()) {
+            let radial = Dir::try_from(point.0.map(|length| length.get::<meter>())).unwrap();
+            assert!(normal.dot(radial) > 0.99);
+        }
+    }
+
+    fn grid(size: usize) -> RenderMesh {
+        let mut mesh = RenderMesh::empty();
+        for y in 0..=size {
+            for x in 0..=size {
+                let (u, v) = (x as f64 / size as f64, y as f64 / size as f64);
+                mesh.points.push(point!(u.m(), v.m(), 0.m()));
+                mesh.normals.push(dir!(0, 0, 1));
+                mesh.uvs.push([u, v]);
+            }
+        }
+        for y in 0..size {
+            for x in 0..size {
+                let corner = y * (size + 1) + x;
+                let above = corner + size + 1;
+                mesh.indices.push([corner, corner + 1, above + 1]);
+                mesh.indices.push([corner, above + 1, above]);
+            }
+        }
+        mesh
+    }
+
+    #[test]
+    fn decimate_grid_preserves_boundary() {
+        let mesh = grid(10);
+        let decimated = mesh.decimate(50);
+
+        assert!(decimated.indices().len() < mesh.indices().len());
+        assert!(decimated.indices().len() >= 40);
+        assert_relative_eq!(decimated.area(), 1., epsilon = 1e-9);
+        for point in mesh.points() {
+            let x = point.x().get::<meter>();
+            let y = point.y().get::<meter>();
+            if x == 0. || x == 1. || y == 0. || y == 1. {
+                assert!(decimated.points().contains(point));
+            }
+        }
+        assert!(
+            decimated
+                .normals()
+                .iter()
+                .all(|normal| *normal == dir!(0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn decimate_to_larger_target() {
+        let mesh = grid(2);
+        assert_eq!(mesh.decimate(100), mesh);
+    }
+
+    #[test]
+    fn decimate_sphere() {
+        let mesh = RenderMesh::try_from((Sphere::from_radius(1.m()), 0.001.m())).unwrap();
+        let target = mesh.indices().len() / 4;
+        let decimated = mesh.decimate(target);
+
+        assert!(decimated.indices().len() <= target);
+        assert_eq!(decimated.normals().len(), decimated.points().len());
+        assert_abs_diff_eq!(decimated.center(), point!(0, 0, 0), epsilon = 0.05);
+    }
+
+    #[test]
+    fn volume_tetrahedron() {
+        assert_relative_eq!(tetrahedron().volume(), 1. / 6.);
+        assert_relative_eq!(tetrahedron().flip_winding().volume(), -1. / 6.);
+    }
+
+    #[test]
+    fn volume_open_mesh() {
+        assert_relative_eq!(grid(4).volume(), 0.);
+    }
+
+    #[test]
+    fn volume_cube() {
+        let cube = Cube::from_size(2.m()).move_to(point!(3.m(), 1.m(), -2.m()));
+        let mesh = RenderMesh::try_from(cube.clone()).unwrap();
+        assert_relative_eq!(mesh.volume(), 8., epsilon = 1e-9);
+        assert_relative_eq!(mesh.volume(), cube.volume().value, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn volume_sphere() {
+        let sphere = Sphere::from_radius(1.m());
+        let mesh = RenderMesh::try_from((sphere.clone(), 0.001.m())).unwrap();
+        assert_relative_eq!(mesh.volume(), sphere.volume().value, max_relative = 0.01);
+    }
+
+    #[test]
+    fn merge() {
+        let mesh = RenderMesh::try_from(Cube::from_size(1.m())).unwrap();
+        let other =
+            RenderMesh::try_from(Cube::from_size(2.m()).move_to(point!(3.m(), 0.m(), 0.m())))
+                .unwrap();
+        let merged = RenderMesh::merge(vec![mesh.clone(), other.clone()]);
+
+        assert_eq!(
+            merged.points().len(),
+            mesh.points().len() + other.points().len()
+        );
+        assert_eq!(merged.normals().len(), merged.points().len());
+        assert_eq!(merged.uvs().len(), merged.points().len());
+        assert_eq!(
+            merged.indices().len(),
+            mesh.indices().len() + other.indices().len()
+        );
+        assert!(
+            merged
+                .indices()
+                .iter()
+                .flatten()
+                .all(|index| *index < merged.points().len())
+        );
+        assert_relative_eq!(merged.area(), mesh.area() + other.area());
+    }
+
+    #[test]
+    fn merge_empty() {
+        assert_eq!(RenderMesh::merge(vec![]), RenderMesh::empty());
+    }
+
+    #[test]
+    fn try_from_with_uv_planar() {
+        let face = Rectangle::from_corners(point!(1.m(), 1.m()), point!(4.m(), 3.m()))
+            .to_face(Plane::xy())
+            .unwrap();
+        let mesh = RenderMesh::try_from_with_uv(
+            face,
+            MeshOptions::DEFAULT_TOLERANCE,
+            UvMode::Planar(Plane::xy()),
+        )
+        .unwrap();
+        for (point, uv) in mesh.points().iter().zip(mesh.uvs()) {
+            assert_relative_eq!(uv[0], point.x().get::<meter>());
+            assert_relative_eq!(uv[1], point.y().get::<meter>());
+        }
+    }
+
+    #[test]
+    fn try_from_with_uv_face() {
+        let face = Rectangle::from_corners(point!(1.m(), 1.m()), point!(4.m(), 3.m()))
+            .to_face(Plane::xy())
+            .unwrap();
+        assert_eq!(
+            RenderMesh::try_from_with_uv(
+                face.clone(),
+                MeshOptions::DEFAULT_TOLERANCE,
+                UvMode::Face
+            ),
+            RenderMesh::try_from(face)
+        );
+    }
+
     #[test]
     fn triangle() {
         let face = Path::at(point!(0, 0))
@@ -0,0 +1,107 @@
+use std::f64::consts::PI;
+
+use uom::si::length::meter;
+
+use crate::{Plane, Point};
+
+/// The method used to assign texture coordinates to the points of a `RenderMesh`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UvMode {
+    /// Normalize the surface coordinates of every face to the range [0, 1].
+    #[default]
+    Face,
+    /// Project every point onto a `Plane`, using its local coordinates in meters.
+    ///
+    /// Textures tile seamlessly across faces parallel to the plane.
+    Planar(Plane),
+    /// Wrap around the normal axis of a `Plane`.
+    ///
+    /// The u-coordinate is the angle around the axis starting at the x-direction of the plane,
+    /// scaled to the range [0, 1). The v-coordinate is the height above the plane in meters.
+    Cylindrical(Plane),
+    /// Wrap around the origin of a `Plane`.
+    ///
+    /// The u-coordinate is the angle around the normal axis starting at the x-direction of the
+    /// plane, scaled to the range [0, 1). The v-coordinate is the latitude, scaled from 0 at the
+    /// pole opposite the normal to 1 at the pole in normal direction.
+    Spherical(Plane),
+}
+impl UvMode {
+    /// Return the texture coordinates of a `Point` or `None` if they depend on the face.
+    pub(crate) fn uv(&self, point: Point<3>) -> Option<[f64; 2]> {
+        let to_local = |plane: &Plane| {
+            let offset = (point - plane.origin())
+                .0
+                .map(|length| length.get::<meter>());
+            [plane.x(), plane.y(), plane.normal()]
+                .map(|dir| dir.0.iter().zip(offset).map(|(a, b)| a * b).sum::<f64>())
+        };
+        let longitude = |x: f64, y: f64| y.atan2(x).rem_euclid(2. * PI) / (2. * PI);
+
+        match self {
+            Self::Face => None,
+            Self::Planar(plane) => {
+                let [x, y, _] = to_local(plane);
+                Some([x, y])
+            }
+            Self::Cylindrical(plane) => {
+                let [x, y, z] = to_local(plane);
+                Some([longitude(x, y), z])
+            }
+            Self::Spherical(plane) => {
+                let [x, y, z] = to_local(plane);
+                let radius = (x * x + y * y + z * z).sqrt();
+                let latitude = if radius == 0. {
+                    0.5
+                } else {
+                    (z / radius).acos() / PI
+                };
+                Some([longitude(x, y), 1. - latitude])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{IntoLength, dir, point};
+
+    #[test]
+    fn face() {
+        assert_eq!(UvMode::Face.uv(point!(1.m(), 2.m(), 3.m())), None);
+    }
+
+    #[test]
+    fn planar() {
+        let plane = Plane::new(point!(1.m(), 0.m(), 0.m()), dir!(0, 1, 0), dir!(0, 0, 1)).unwrap();
+        assert_eq!(
+            UvMode::Planar(plane).uv(point!(5.m(), 2.m(), 3.m())),
+            Some([2., 3.])
+        );
+    }
+
+    #[test]
+    fn cylindrical() {
+        let mode = UvMode::Cylindrical(Plane::xy());
+        let [u, v] = mode.uv(point!(0.m(), 2.m(), 3.m())).unwrap();
+        assert_relative_eq!(u, 0.25);
+        assert_relative_eq!(v, 3.);
+
+        let [u, _] = mode.uv(point!(0.m(), -1.m(), 0.m())).unwrap();
+        assert_relative_eq!(u, 0.75);
+    }
+
+    #[test]
+    fn spherical() {
+        let mode = UvMode::Spherical(Plane::xy());
+        assert_eq!(mode.uv(point!(0.m(), 0.m(), 1.m())), Some([0., 1.]));
+        assert_eq!(mode.uv(point!(0, 0, 0)), Some([0., 0.5]));
+
+        let [u, v] = mode.uv(point!(-1.m(), 0.m(), 0.m())).unwrap();
+        assert_relative_eq!(u, 0.5);
+        assert_relative_eq!(v, 0.5);
+    }
+}
@@ -0,0 +1,15 @@
+/// A single vertex of a `RenderMesh` in a layout that can be uploaded to the GPU directly.
+///
+/// With the `bytemuck` feature enabled, slices of `Vertex` can be cast to bytes with
+/// `bytemuck::cast_slice()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Vertex {
+    /// The position of the vertex in meters.
+    pub position: [f32; 3],
+    /// The normal direction of the surface at the vertex.
+    pub normal: [f32; 3],
+    /// The relative position of the vertex on the 2D-grid of its face.
+    pub uv: [f32; 2],
+}
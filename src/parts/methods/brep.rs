@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use opencascade_sys::ffi;
+
+use crate::{Error, Part};
+
+impl Part {
+    /// Write the `Part` to a file in the native BREP format of OpenCASCADE.
+    ///
+    /// Unlike STEP or STL, BREP files store the exact geometry without any conversion, which makes
+    /// them suitable for caching intermediate results.
+    pub fn write_brep(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        match &self.inner {
+            Some(inner) => {
+                let success = ffi::write_brep(inner, path.as_ref().to_string_lossy().to_string());
+                if success {
+                    Ok(())
+                } else {
+                    Err(Error::BrepWrite(path.as_ref().to_path_buf()))
+                }
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+
+    /// Read a `Part` from a file in the native BREP format of OpenCASCADE.
+    pub fn read_brep(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut shape = ffi::TopoDS_Shape_ctor();
+        let success = ffi::read_brep(path.as_ref().to_string_lossy().to_string(), shape.pin_mut());
+        if success {
+            Ok(Self::from_occt(&shape))
+        } else {
+            Err(Error::BrepRead(path.as_ref().to_path_buf()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::NamedTempFile;
+
+    use super::*;
+    use crate::{Cuboid, Cylinder, IntoLength, Sphere, point};
+
+    #[test]
+    fn write_read_roundtrip() {
+        let part = Cylinder::from_radius(1.m(), 2.m())
+            .subtract(&Sphere::from_radius(0.5.m()).move_to(point!(0.m(), 0.m(), 1.m())))
+            .add(&Cuboid::from_dim(0.5.m(), 3.m(), 0.5.m()));
+        let file = NamedTempFile::new().expect("could not create tempfile");
+
+        part.write_brep(file.path()).unwrap();
+        assert_eq!(Part::read_brep(file.path()), Ok(part));
+    }
+
+    #[test]
+    fn write_empty() {
+        let file = NamedTempFile::new().expect("could not create tempfile");
+        assert_eq!(Part::empty().write_brep(file.path()), Err(Error::EmptyPart));
+    }
+
+    #[test]
+    fn read_missing_file() {
+        assert_eq!(
+            Part::read_brep("does/not/exist.brep"),
+            Err(Error::BrepRead("does/not/exist.brep".into()))
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use opencascade_sys::ffi;
 use uom::si::length::meter;
+use uom::si::volume::cubic_meter;
 
 use crate::{Error, Length, Part, Point, point};
 
@@ -21,16 +21,14 @@ impl Part {
     /// assert_eq!(non_centered_cuboid.center(), Ok(point!(1.m(), 1.m(), 1.m())));
     /// ```
     pub fn center(&self) -> Result<Point<3>, Error> {
-        match &self.inner {
-            Some(inner) => {
-                let mut gprops = ffi::GProp_GProps_ctor();
-                ffi::BRepGProp_VolumeProperties(inner, gprops.pin_mut());
-                let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&gprops);
-
+        match self.mass_properties() {
+            Some(properties) => {
+                let scale = properties.volume.get::<cubic_meter>().cbrt();
+                let center = properties.center;
                 Ok(point!(
-                    Length::new::<meter>(round(centre_of_mass.X(), 9)),
-                    Length::new::<meter>(round(centre_of_mass.Y(), 9)),
-                    Length::new::<meter>(round(centre_of_mass.Z(), 9))
+                    Length::new::<meter>(round(center.x().get::<meter>(), scale)),
+                    Length::new::<meter>(round(center.y().get::<meter>(), scale)),
+                    Length::new::<meter>(round(center.z().get::<meter>(), scale))
                 ))
             }
             None => Err(Error::EmptyPart),
@@ -38,12 +36,21 @@ impl Part {
     }
 }
 
-fn round(x: f64, n_digits: u8) -> f64 {
-    (x * f64::from(10 ^ n_digits)).round() / f64::from(10 ^ n_digits)
+/// Round a coordinate to nine significant digits relative to the size of the `Part`.
+///
+/// This removes numerical noise of OCCT without losing precision for very small parts. If the
+/// scale is zero or not finite, the coordinate is returned unchanged.
+fn round(x: f64, scale: f64) -> f64 {
+    if !scale.is_normal() {
+        return x;
+    }
+    let factor = 10_f64.powi(9 - scale.log10().floor() as i32);
+    (x * factor).round() / factor
 }
 #[cfg(test)]
 mod tests {
-    use crate::{Cuboid, IntoLength, point};
+    use super::*;
+    use crate::{Cuboid, IntoLength};
 
     #[test]
     fn centre_at_origin() {
@@ -56,4 +63,29 @@ mod tests {
         let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 2.m()));
         assert_eq!(cuboid.center(), Ok(point!(1.m(), 1.m(), 1.m())))
     }
+
+    #[test]
+    fn centre_of_micrometer_offset() {
+        let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m()).move_to(point!(1.um(), 0.m(), 0.m()));
+        assert_eq!(cuboid.center(), Ok(point!(1.um(), 0.m(), 0.m())))
+    }
+
+    #[test]
+    fn centre_of_micrometer_part() {
+        let cuboid =
+            Cuboid::from_dim(10.um(), 10.um(), 10.um()).move_to(point!(3.um(), 0.m(), 0.m()));
+        let center = cuboid.center().unwrap();
+        assert!(center.x() > 0.m());
+        assert_eq!(center, point!(3.um(), 0.m(), 0.m()))
+    }
+
+    #[test]
+    fn round_relative_to_scale() {
+        assert_eq!(round(1.0000000000001, 1.), 1.);
+        assert_eq!(round(0.3, 1.), 0.3);
+        assert_eq!(round(1e-6, 1.), 1e-6);
+        assert_eq!(round(2.5e-9, 1e-5), 2.5e-9);
+        assert_eq!(round(1e-20, 1e-5), 0.);
+        assert_eq!(round(0.123, 0.), 0.123);
+    }
 }
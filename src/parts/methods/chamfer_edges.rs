@@ -0,0 +1,116 @@
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{Edge3D, Error, Length, Part};
+
+impl Part {
+    /// Return a copy of this `Part` with the edges matching a predicate chamfered.
+    ///
+    /// Every selected edge is replaced by a flat face that cuts the given distance into both
+    /// adjacent faces. If the distance is not positive or the chamfer can not be constructed, e.g.
+    /// because it is larger than the adjacent faces, an `Error::Chamfer` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, Edge3D, IntoLength, dir};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let cube = Cube::from_size(2.m());
+    /// let vertical = |edge: &Edge3D| {
+    ///     edge.direction().is_ok_and(|direction| direction.dot(dir!(0, 0, 1)).abs() > 0.999)
+    /// };
+    /// let chamfered = cube.chamfer_edges(0.5.m(), vertical).unwrap();
+    /// assert_relative_eq!(chamfered.volume().value, 8. - 4. * 0.125 * 2., max_relative = 1e-7);
+    /// ```
+    pub fn chamfer_edges(
+        &self,
+        distance: Length,
+        predicate: impl Fn(&Edge3D) -> bool,
+    ) -> Result<Self, Error> {
+        let inner = match &self.inner {
+            Some(inner) => inner,
+            None => return Err(Error::EmptyPart),
+        };
+        if distance <= Length::new::<meter>(0.) {
+            return Err(Error::Chamfer);
+        }
+
+        let mut make_chamfer = ffi::BRepFilletAPI_MakeChamfer_ctor(inner);
+        let mut explorer = ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_EDGE);
+        let mut any_selected = false;
+        while explorer.More() {
+            let edge = ffi::TopoDS_cast_to_edge(explorer.Current());
+            if predicate(&Edge3D::from_occt(edge)) {
+                // edges shared by two faces are visited twice, OCCT ignores the second addition
+                make_chamfer
+                    .pin_mut()
+                    .add_edge(distance.get::<meter>(), edge);
+                any_selected = true;
+            }
+            explorer.pin_mut().Next();
+        }
+        if !any_selected {
+            return Ok(self.clone());
+        }
+
+        make_chamfer
+            .pin_mut()
+            .Build(&ffi::Message_ProgressRange_ctor());
+        if !make_chamfer.IsDone() {
+            return Err(Error::Chamfer);
+        }
+        Ok(Self::from_occt(make_chamfer.pin_mut().Shape()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cuboid, IntoLength, dir};
+
+    fn is_vertical(edge: &Edge3D) -> bool {
+        edge.direction()
+            .is_ok_and(|direction| direction.dot(dir!(0, 0, 1)).abs() > 0.999)
+    }
+
+    #[test]
+    fn chamfer_vertical_edges_of_box() {
+        let cuboid = Cuboid::from_dim(4.m(), 4.m(), 2.m());
+        let chamfered = cuboid.chamfer_edges(1.m(), is_vertical).unwrap();
+
+        // every vertical edge removes a prism with a triangular base of 0.5 m²
+        assert_relative_eq!(
+            chamfered.volume().value,
+            32. - 4. * 0.5 * 2.,
+            max_relative = 1e-7
+        );
+        // chamfering the top or bottom rims would add further faces
+        assert_eq!(chamfered.faces().len(), 10);
+        assert_eq!(chamfered.topology().edges, 24);
+    }
+
+    #[test]
+    fn chamfer_no_edges() {
+        let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let chamfered = cuboid.chamfer_edges(0.1.m(), |_| false).unwrap();
+        assert_eq!(chamfered.faces().len(), 6);
+    }
+
+    #[test]
+    fn chamfer_zero_distance() {
+        let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert!(matches!(
+            cuboid.chamfer_edges(0.m(), |_| true),
+            Err(Error::Chamfer)
+        ));
+    }
+
+    #[test]
+    fn chamfer_empty_part() {
+        assert!(matches!(
+            Part::empty().chamfer_edges(1.m(), |_| true),
+            Err(Error::EmptyPart)
+        ));
+    }
+}
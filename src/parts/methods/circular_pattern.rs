@@ -3,6 +3,9 @@ use crate::{Axis, IntoAngle, Part};
 impl Part {
     /// Create multiple instances of the `Part` spaced evenly around a point.
     ///
+    /// The original `Part` counts as the first instance. If the number of instances is zero, the
+    /// `Part` is returned unchanged.
+    ///
     /// ```rust
     /// use anvil::{Axis, Cuboid, IntoAngle, IntoLength, point};
     ///
@@ -16,13 +19,34 @@ impl Part {
     /// )
     /// ```
     pub fn circular_pattern(&self, around: Axis<3>, instances: u8) -> Self {
+        if instances == 0 {
+            return self.clone();
+        }
         let angle_step = 360.deg() / instances as f64;
         let mut new_shape = self.clone();
-        let mut angle = 0.rad();
-        for _ in 0..instances {
-            new_shape = new_shape.add(&self.rotate_around(around, angle));
-            angle += angle_step;
+        for i in 1..instances {
+            new_shape = new_shape.add(&self.rotate_around(around, angle_step * i as f64));
         }
         new_shape
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Cuboid, IntoLength, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn four_instances() {
+        let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+        let pattern = cuboid.circular_pattern(Axis::<3>::z(), 4);
+        assert_relative_eq!(pattern.volume().value, 4. * cuboid.volume().value);
+    }
+
+    #[test]
+    fn zero_and_one_instances() {
+        let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+        assert_eq!(cuboid.circular_pattern(Axis::<3>::z(), 0), cuboid);
+        assert_eq!(cuboid.circular_pattern(Axis::<3>::z(), 1), cuboid);
+    }
+}
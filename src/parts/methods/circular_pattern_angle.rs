@@ -0,0 +1,92 @@
+use crate::{Angle, Axis, IntoAngle, Part};
+
+impl Part {
+    /// Create multiple instances of the `Part` spread evenly across an angle around an axis.
+    ///
+    /// The first instance is the original `Part` and the last one is rotated by the total angle.
+    /// If the total angle is a full turn or more, the last instance would coincide with the first,
+    /// so the angle is clamped to a full turn and the instances are spaced like in
+    /// `Part::circular_pattern` instead.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, Cuboid, IntoAngle, IntoLength, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+    /// assert_eq!(
+    ///     cuboid.circular_pattern_angle(Axis::<3>::z(), 3, 90.deg()),
+    ///     cuboid
+    ///         .add(&cuboid.rotate_around(Axis::<3>::z(), 45.deg()))
+    ///         .add(&cuboid.rotate_around(Axis::<3>::z(), 90.deg()))
+    /// )
+    /// ```
+    pub fn circular_pattern_angle(
+        &self,
+        around: Axis<3>,
+        instances: u8,
+        total_angle: Angle,
+    ) -> Self {
+        if instances < 2 {
+            return self.clone();
+        }
+        let angle_step = if total_angle.abs() >= 360.deg() {
+            360.deg() * total_angle.signum().value / instances as f64
+        } else {
+            total_angle / (instances - 1) as f64
+        };
+
+        let mut new_shape = self.clone();
+        let mut angle = 0.rad();
+        for _ in 1..instances {
+            angle += angle_step;
+            new_shape = new_shape.add(&self.rotate_around(around, angle));
+        }
+        new_shape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Cuboid, IntoAngle, IntoLength, point};
+
+    #[test]
+    fn four_instances_over_quarter_turn() {
+        let cuboid =
+            Cuboid::from_corners(point!(1.m(), 0.m(), 0.m()), point!(2.m(), 0.1.m(), 1.m()));
+        assert_eq!(
+            cuboid.circular_pattern_angle(Axis::<3>::z(), 4, 90.deg()),
+            cuboid
+                .add(&cuboid.rotate_around(Axis::<3>::z(), 30.deg()))
+                .add(&cuboid.rotate_around(Axis::<3>::z(), 60.deg()))
+                .add(&cuboid.rotate_around(Axis::<3>::z(), 90.deg()))
+        );
+    }
+
+    #[test]
+    fn full_turn_matches_circular_pattern() {
+        let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+        assert_eq!(
+            cuboid.circular_pattern_angle(Axis::<3>::z(), 4, 360.deg()),
+            cuboid.circular_pattern(Axis::<3>::z(), 4)
+        );
+    }
+
+    #[test]
+    fn more_than_full_turn_is_clamped() {
+        let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+        for angle in [370.deg(), 720.deg()] {
+            assert_eq!(
+                cuboid.circular_pattern_angle(Axis::<3>::z(), 4, angle),
+                cuboid.circular_pattern(Axis::<3>::z(), 4)
+            );
+        }
+    }
+
+    #[test]
+    fn single_instance() {
+        let cuboid = Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(2.m(), 2.m(), 1.m()));
+        assert_eq!(
+            cuboid.circular_pattern_angle(Axis::<3>::z(), 1, 90.deg()),
+            cuboid
+        );
+    }
+}
@@ -1,10 +1,46 @@
 use crate::Part;
 
 impl Clone for Part {
+    /// Return a `Part` that shares the underlying shape with this one.
+    ///
+    /// As `Part`s are immutable, the shape is never copied, so cloning is cheap regardless of the
+    /// complexity of the `Part`.
     fn clone(&self) -> Self {
-        match &self.inner {
-            Some(inner) => Self::from_occt(inner),
-            None => Part { inner: None },
+        Self {
+            inner: self.inner.clone(),
+            properties: self.properties.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{Cube, IntoLength, Part};
+
+    #[test]
+    fn clone_shares_shape() {
+        let cube = Cube::from_size(1.m());
+        let clone = cube.clone();
+        assert!(Rc::ptr_eq(
+            cube.inner.as_ref().unwrap(),
+            clone.inner.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn transform_does_not_share_shape() {
+        let cube = Cube::from_size(1.m());
+        let moved = cube.move_by(1.m(), 0.m(), 0.m());
+        assert!(!Rc::ptr_eq(
+            cube.inner.as_ref().unwrap(),
+            moved.inner.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn clone_empty() {
+        assert!(Part::empty().clone().inner.is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use opencascade_sys::ffi;
+
+use crate::Part;
+
+impl Part {
+    /// Combine multiple `Part`s into one without fusing them.
+    ///
+    /// Unlike `Part::union_all()`, no boolean operation is performed. This is much faster and
+    /// keeps touching or overlapping `Part`s as separate solids, which is useful for assemblies.
+    /// Empty `Part`s are skipped and an empty slice results in an empty `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, Part, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let cube1 = Cube::from_size(1.m());
+    /// let cube2 = cube1.move_to(point!(3.m(), 0.m(), 0.m()));
+    /// let compound = Part::compound(&[cube1, cube2]);
+    /// assert_relative_eq!(compound.volume().value, 2.);
+    /// assert_eq!(compound.topology().solids, 2);
+    /// ```
+    pub fn compound(parts: &[Self]) -> Self {
+        let inners: Vec<&ffi::TopoDS_Shape> = parts
+            .iter()
+            .filter_map(|part| part.inner.as_deref())
+            .map(|inner| &**inner)
+            .collect();
+        if inners.is_empty() {
+            return Self::empty();
+        }
+
+        let builder = ffi::BRep_Builder_ctor();
+        let builder = ffi::BRep_Builder_upcast_to_topods_builder(&builder);
+        let mut compound = ffi::TopoDS_Compound_ctor();
+        builder.MakeCompound(compound.pin_mut());
+
+        let mut shape = ffi::TopoDS_Compound_as_shape(compound);
+        for inner in inners {
+            builder.Add(shape.pin_mut(), inner);
+        }
+        Self::from_occt(&shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cube, IntoLength, Part, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn separated_cubes() {
+        let cube1 = Cube::from_size(1.m());
+        let cube2 = cube1.move_to(point!(3.m(), 0.m(), 0.m()));
+        let compound = Part::compound(&[cube1.clone(), cube2.clone()]);
+
+        assert_relative_eq!(compound.volume().value, 2.);
+        assert_eq!(compound.topology().solids, 2);
+        assert_eq!(compound.faces().len(), 12);
+        assert_eq!(compound.triangulate().unwrap().indices().len(), 24);
+        assert_eq!(compound, cube1.add(&cube2));
+    }
+
+    #[test]
+    fn touching_cubes_stay_separate() {
+        let cube1 = Cube::from_size(1.m());
+        let cube2 = cube1.move_to(point!(1.m(), 0.m(), 0.m()));
+        assert_eq!(Part::compound(&[cube1, cube2]).topology().solids, 2);
+    }
+
+    #[test]
+    fn skips_empty_parts() {
+        let cube = Cube::from_size(1.m());
+        assert_eq!(Part::compound(&[Part::empty(), cube.clone()]), cube);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Part::compound(&[]), Part::empty());
+        assert_eq!(Part::compound(&[Part::empty()]), Part::empty());
+    }
+}
@@ -0,0 +1,60 @@
+use opencascade_sys::ffi;
+
+use crate::{Part, Point};
+
+impl Part {
+    /// Return true if a `Point` lies inside this `Part`.
+    ///
+    /// Points on the surface of the `Part` are considered to be contained. An empty `Part` does
+    /// not contain any point.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, point};
+    ///
+    /// let cube = Cube::from_size(2.m());
+    /// assert!(cube.contains(point!(0, 0, 0)));
+    /// assert!(cube.contains(point!(1.m(), 0.m(), 0.m())));
+    /// assert!(!cube.contains(point!(5.m(), 5.m(), 5.m())));
+    /// ```
+    pub fn contains(&self, point: Point<3>) -> bool {
+        match &self.inner {
+            Some(inner) => {
+                let classifier =
+                    ffi::BRepClass3d_SolidClassifier_ctor(inner, &point.to_occt_point(), 1e-7);
+                matches!(
+                    classifier.State(),
+                    ffi::TopAbs_State::TopAbs_IN | ffi::TopAbs_State::TopAbs_ON
+                )
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cube, IntoLength, point};
+
+    #[test]
+    fn contains_center_of_cube() {
+        assert!(Cube::from_size(1.m()).contains(point!(0, 0, 0)));
+    }
+
+    #[test]
+    fn contains_far_point() {
+        assert!(!Cube::from_size(1.m()).contains(point!(10.m(), 0.m(), 0.m())));
+    }
+
+    #[test]
+    fn contains_hollow_cube() {
+        let hollow_cube = Cube::from_size(2.m()).hollow(0.5.m()).unwrap();
+        assert!(!hollow_cube.contains(point!(0, 0, 0)));
+        assert!(hollow_cube.contains(point!(0.75.m(), 0.m(), 0.m())));
+    }
+
+    #[test]
+    fn contains_empty_part() {
+        assert!(!Part::empty().contains(point!(0, 0, 0)));
+    }
+}
@@ -0,0 +1,434 @@
+use std::collections::{HashMap, HashSet};
+
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{Error, IndexedMesh, MeshOptions, Part};
+
+impl Part {
+    /// Return the smallest convex `Part` that contains this one.
+    ///
+    /// The hull is computed from the points of a coarse triangulation of the `Part`, so curved
+    /// surfaces are approximated by planar faces. A `Part` whose points all lie in one plane has no
+    /// hull with a volume and results in `Error::DegenerateHull`, as does a point cloud for which no
+    /// consistent hull can be computed in floating point arithmetic.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, point};
+    ///
+    /// let cube1 = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+    /// let cube2 = cube1.move_to(point!(3.5.m(), 0.5.m(), 0.5.m()));
+    /// assert_eq!(
+    ///     cube1.add(&cube2).convex_hull(),
+    ///     Ok(Cuboid::from_corners(point!(0, 0, 0), point!(4.m(), 1.m(), 1.m())))
+    /// );
+    /// ```
+    pub fn convex_hull(&self) -> Result<Self, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+
+        // faces are triangulated separately, so their shared points are merged to keep the hull
+        // from having to process each of them repeatedly
+        let mesh = IndexedMesh::from_render_mesh(&self.render_mesh(MeshOptions::COARSE)?);
+        let points: Vec<[f64; 3]> = mesh
+            .points()
+            .iter()
+            .map(|point| {
+                [
+                    point.x().get::<meter>(),
+                    point.y().get::<meter>(),
+                    point.z().get::<meter>(),
+                ]
+            })
+            .collect();
+
+        let triangles = hull_triangles(&points).ok_or(Error::DegenerateHull)?;
+        let polygons = merge_coplanar(&points, &triangles);
+
+        let mut sewing = ffi::BRepBuilderAPI_Sewing_ctor(1e-7);
+        for polygon in polygons {
+            let mut make_wire = ffi::BRepBuilderAPI_MakeWire_ctor();
+            for i in 0..polygon.len() {
+                let start = points[polygon[i]];
+                let end = points[polygon[(i + 1) % polygon.len()]];
+                let mut make_edge = ffi::BRepBuilderAPI_MakeEdge_gp_Pnt_gp_Pnt(
+                    &ffi::new_point(start[0], start[1], start[2]),
+                    &ffi::new_point(end[0], end[1], end[2]),
+                );
+                make_wire.pin_mut().add_edge(make_edge.pin_mut().Edge());
+            }
+            let wire = ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire());
+            let make_face = ffi::BRepBuilderAPI_MakeFace_wire(&wire, true);
+            sewing
+                .pin_mut()
+                .Add(ffi::cast_face_to_shape(make_face.Face()));
+        }
+        sewing.pin_mut().Perform();
+
+        let shell = ffi::TopoDS_cast_to_shell(sewing.SewedShape());
+        let mut make_solid = ffi::BRepBuilderAPI_MakeSolid_ctor(shell);
+        Ok(Self::from_occt(ffi::cast_solid_to_shape(
+            make_solid.pin_mut().Solid(),
+        )))
+    }
+}
+
+/// A triangle of a convex hull under construction together with the points outside of it.
+struct HullFace {
+    corners: [usize; 3],
+    outside: Vec<usize>,
+    is_alive: bool,
+}
+
+/// Compute the outward oriented triangles of the convex hull around a set of points.
+///
+/// Every point that is not yet enclosed is kept in the conflict list of exactly one face it lies
+/// in front of, so each step only needs to look at the faces seen from the farthest point of a
+/// face and at the points of the faces it replaces.
+///
+/// Returns `None` if all points lie in a common plane or if rounding errors break the connectivity
+/// of the hull.
+fn hull_triangles(points: &[[f64; 3]]) -> Option<Vec<[usize; 3]>> {
+    let scale = points
+        .iter()
+        .flatten()
+        .fold(0_f64, |max, coordinate| max.max(coordinate.abs()))
+        .max(1.);
+    let tolerance = scale * 1e-9;
+
+    let p0 = 0;
+    let p1 = farthest(points, |p| norm(sub(p, points[p0])))?;
+    let p2 = farthest(points, |p| {
+        norm(cross(sub(points[p1], points[p0]), sub(p, points[p0])))
+    })?;
+    let normal = cross(sub(points[p1], points[p0]), sub(points[p2], points[p0]));
+    let p3 = farthest(points, |p| dot(normal, sub(p, points[p0])).abs())?;
+    if dot(normal, sub(points[p3], points[p0])).abs() <= tolerance * norm(normal) {
+        return None;
+    }
+
+    let tetrahedron = if dot(normal, sub(points[p3], points[p0])) > 0. {
+        [[p0, p2, p1], [p0, p1, p3], [p1, p2, p3], [p2, p0, p3]]
+    } else {
+        [[p0, p1, p2], [p0, p3, p1], [p1, p3, p2], [p2, p3, p0]]
+    };
+    let mut faces: Vec<HullFace> = vec![];
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    let corners = [p0, p1, p2, p3];
+    let remaining = (0..points.len()).filter(|i| !corners.contains(i)).collect();
+    let mut pending = add_faces(
+        points,
+        &mut faces,
+        &mut edges,
+        &tetrahedron,
+        remaining,
+        tolerance,
+    );
+
+    while let Some(face) = pending.pop() {
+        if !faces[face].is_alive || faces[face].outside.is_empty() {
+            continue;
+        }
+        let eye = *faces[face]
+            .outside
+            .iter()
+            .max_by(|a, b| {
+                let distance = |i: &usize| signed_distance(points, faces[face].corners, points[*i]);
+                distance(a).total_cmp(&distance(b))
+            })
+            .expect("the conflict list is not empty");
+
+        // the faces visible from the eye form a connected patch around the face it belongs to,
+        // bounded by the horizon
+        let mut visible = vec![face];
+        let mut horizon = vec![];
+        faces[face].is_alive = false;
+        let mut i = 0;
+        while i < visible.len() {
+            let [a, b, c] = faces[visible[i]].corners;
+            for (start, end) in [(a, b), (b, c), (c, a)] {
+                // rounding errors can leave the visible faces without a closed horizon
+                let neighbor = *edges.get(&(end, start))?;
+                if !faces[neighbor].is_alive {
+                    continue;
+                }
+                if signed_distance(points, faces[neighbor].corners, points[eye]) > tolerance {
+                    faces[neighbor].is_alive = false;
+                    visible.push(neighbor);
+                } else {
+                    horizon.push((start, end));
+                }
+            }
+            i += 1;
+        }
+
+        let mut orphans = vec![];
+        for face in visible {
+            let [a, b, c] = faces[face].corners;
+            for edge in [(a, b), (b, c), (c, a)] {
+                edges.remove(&edge);
+            }
+            orphans.append(&mut faces[face].outside);
+        }
+        orphans.retain(|i| *i != eye);
+
+        let new_faces: Vec<[usize; 3]> = horizon.iter().map(|(a, b)| [*a, *b, eye]).collect();
+        pending.extend(add_faces(
+            points, &mut faces, &mut edges, &new_faces, orphans, tolerance,
+        ));
+    }
+
+    Some(
+        faces
+            .into_iter()
+            .filter(|face| face.is_alive)
+            .map(|face| face.corners)
+            .collect(),
+    )
+}
+
+/// Add triangles to a hull under construction and distribute points among their conflict lists.
+///
+/// Points that lie behind all of the new triangles are enclosed by the hull and dropped. Returns
+/// the indices of the new faces that have points outside of them.
+fn add_faces(
+    points: &[[f64; 3]],
+    faces: &mut Vec<HullFace>,
+    edges: &mut HashMap<(usize, usize), usize>,
+    triangles: &[[usize; 3]],
+    candidates: Vec<usize>,
+    tolerance: f64,
+) -> Vec<usize> {
+    let first = faces.len();
+    for corners in triangles {
+        let [a, b, c] = *corners;
+        for edge in [(a, b), (b, c), (c, a)] {
+            edges.insert(edge, faces.len());
+        }
+        faces.push(HullFace {
+            corners: *corners,
+            outside: vec![],
+            is_alive: true,
+        });
+    }
+
+    for candidate in candidates {
+        if let Some(face) = faces[first..]
+            .iter_mut()
+            .find(|face| signed_distance(points, face.corners, points[candidate]) > tolerance)
+        {
+            face.outside.push(candidate);
+        }
+    }
+
+    (first..faces.len())
+        .filter(|face| !faces[*face].outside.is_empty())
+        .collect()
+}
+
+/// Merge adjacent triangles that lie in the same plane into polygons.
+///
+/// The indices of every polygon are ordered counter clockwise around its outward normal.
+fn merge_coplanar(points: &[[f64; 3]], triangles: &[[usize; 3]]) -> Vec<Vec<usize>> {
+    let mut planes: Vec<([f64; 3], f64, HashSet<usize>)> = vec![];
+    for triangle in triangles {
+        let normal = unit(triangle_normal(points, *triangle));
+        let offset = dot(normal, points[triangle[0]]);
+        match planes.iter_mut().find(|(other_normal, other_offset, _)| {
+            norm(sub(normal, *other_normal)) < 1e-9
+                && (offset - other_offset).abs() < 1e-9 * offset.abs().max(1.)
+        }) {
+            Some((_, _, indices)) => indices.extend(triangle),
+            None => planes.push((normal, offset, HashSet::from(*triangle))),
+        }
+    }
+
+    planes
+        .into_iter()
+        .map(|(normal, _, indices)| {
+            let indices: Vec<usize> = indices.into_iter().collect();
+            let center = indices
+                .iter()
+                .fold([0.; 3], |sum, i| add(sum, points[*i]))
+                .map(|coordinate| coordinate / indices.len() as f64);
+            let u = unit(sub(points[indices[0]], center));
+            let v = cross(normal, u);
+
+            let mut sorted = indices;
+            sorted.sort_by(|a, b| {
+                let angle = |i: &usize| {
+                    let offset = sub(points[*i], center);
+                    dot(offset, v).atan2(dot(offset, u))
+                };
+                angle(a).total_cmp(&angle(b))
+            });
+            sorted
+        })
+        .collect()
+}
+
+fn farthest(points: &[[f64; 3]], distance: impl Fn([f64; 3]) -> f64) -> Option<usize> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, distance(*p)))
+        .filter(|(_, d)| *d > 0.)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, _)| i)
+}
+
+fn signed_distance(points: &[[f64; 3]], triangle: [usize; 3], point: [f64; 3]) -> f64 {
+    dot(
+        unit(triangle_normal(points, triangle)),
+        sub(point, points[triangle[0]]),
+    )
+}
+
+fn triangle_normal(points: &[[f64; 3]], triangle: [usize; 3]) -> [f64; 3] {
+    cross(
+        sub(points[triangle[1]], points[triangle[0]]),
+        sub(points[triangle[2]], points[triangle[0]]),
+    )
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+fn unit(a: [f64; 3]) -> [f64; 3] {
+    let length = norm(a);
+    [a[0] / length, a[1] / length, a[2] / length]
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cube, Cuboid, Cylinder, IntoLength, point};
+
+    fn cube_corners() -> Vec<[f64; 3]> {
+        vec![
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.],
+            [0., 0., 1.],
+            [1., 0., 1.],
+            [1., 1., 1.],
+            [0., 1., 1.],
+        ]
+    }
+
+    #[test]
+    fn hull_triangles_cube_with_interior_points() {
+        let mut points = cube_corners();
+        points.extend([[0.5, 0.5, 0.5], [0.2, 0.7, 0.1], [0.5, 0.5, 1.]]);
+        let triangles = hull_triangles(&points).unwrap();
+
+        for triangle in &triangles {
+            for point in &points {
+                assert!(signed_distance(&points, *triangle, *point) <= 1e-9);
+            }
+        }
+        assert!(triangles.iter().flatten().all(|i| *i != 8 && *i != 9));
+
+        let edges: HashSet<(usize, usize)> = triangles
+            .iter()
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .collect();
+        assert!(edges.iter().all(|(a, b)| edges.contains(&(*b, *a))));
+    }
+
+    #[test]
+    fn hull_triangles_points_on_sphere() {
+        let count = 2000;
+        let points: Vec<[f64; 3]> = (0..count)
+            .map(|i| {
+                let z = 1. - 2. * (i as f64 + 0.5) / count as f64;
+                let radius = (1. - z * z).sqrt();
+                let angle = i as f64 * std::f64::consts::PI * (3. - 5_f64.sqrt());
+                [radius * angle.cos(), radius * angle.sin(), z]
+            })
+            .collect();
+        let triangles = hull_triangles(&points).unwrap();
+
+        assert_eq!(triangles.len(), 2 * count - 4);
+        for triangle in &triangles {
+            assert!(signed_distance(&points, *triangle, [0., 0., 0.]) < 0.);
+        }
+        let edges: HashSet<(usize, usize)> = triangles
+            .iter()
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .collect();
+        assert_eq!(edges.len(), 3 * triangles.len());
+        assert!(edges.iter().all(|(a, b)| edges.contains(&(*b, *a))));
+    }
+
+    #[test]
+    fn hull_triangles_coplanar_points() {
+        let points = vec![[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+        assert_eq!(hull_triangles(&points), None);
+    }
+
+    #[test]
+    fn merge_coplanar_cube() {
+        let points = cube_corners();
+        let polygons = merge_coplanar(&points, &hull_triangles(&points).unwrap());
+        assert_eq!(polygons.len(), 6);
+        for polygon in polygons {
+            assert_eq!(polygon.len(), 4);
+            let normal = triangle_normal(&points, [polygon[0], polygon[1], polygon[2]]);
+            let center = [0.5, 0.5, 0.5];
+            assert!(dot(normal, sub(points[polygon[0]], center)) > 0.);
+        }
+    }
+
+    #[test]
+    fn convex_hull_of_cube() {
+        let cube = Cube::from_size(1.m());
+        assert_eq!(cube.convex_hull(), Ok(cube));
+    }
+
+    #[test]
+    fn convex_hull_of_separated_cubes() {
+        let cube = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+        let cubes = cube.add(&cube.move_to(point!(3.5.m(), 2.5.m(), 0.5.m())));
+        let hull = cubes.convex_hull().unwrap();
+        assert!(hull.volume() > cubes.volume());
+        assert_eq!(hull.intersect(&cubes), cubes);
+    }
+
+    #[test]
+    fn convex_hull_of_cylinder() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let hull = cylinder.convex_hull().unwrap();
+        assert_relative_eq!(
+            hull.volume().value,
+            cylinder.volume().value,
+            max_relative = 1e-2
+        );
+        assert!(hull.volume() <= cylinder.volume());
+    }
+
+    #[test]
+    fn convex_hull_of_empty_part() {
+        assert_eq!(Part::empty().convex_hull(), Err(Error::EmptyPart));
+    }
+}
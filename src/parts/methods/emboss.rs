@@ -0,0 +1,68 @@
+use crate::{Error, Length, Part, Plane, Sketch};
+
+impl Part {
+    /// Return a copy of this `Part` with a raised `Sketch` added to it.
+    ///
+    /// The `Sketch` is placed on the `Plane` and extruded by `height` along the plane normal before
+    /// it is added. The plane should therefore lie on a face of this `Part` with its normal pointing
+    /// outward. If the `Sketch` is empty, the `Part` is returned unchanged, while a `Sketch` that
+    /// can not be extruded, e.g. because it intersects itself, results in an error.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Plane, Rectangle, dir, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+    /// let plane = Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap();
+    /// let label = Rectangle::from_corners(point!(0.5.m(), 0.5.m()), point!(1.5.m(), 1.5.m()));
+    /// assert!(cuboid.emboss(&label, plane, 0.1.m()).unwrap().volume() > cuboid.volume());
+    /// ```
+    pub fn emboss(&self, sketch: &Sketch, plane: Plane, height: Length) -> Result<Self, Error> {
+        match sketch.extrude(plane, height) {
+            Ok(embossing) => Ok(self.add(&embossing)),
+            Err(Error::EmptySketch) => Ok(self.clone()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::{Cuboid, Error, IntoLength, Path, Plane, Rectangle, Sketch, dir, point};
+
+    fn top_plane() -> Plane {
+        Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap()
+    }
+
+    #[test]
+    fn emboss_increases_volume() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        let label = Rectangle::from_corners(point!(0.5.m(), 0.5.m()), point!(1.5.m(), 1.5.m()));
+        let embossed = cuboid.emboss(&label, top_plane(), 0.1.m()).unwrap();
+        assert_relative_eq!(embossed.volume().value, 4. + 0.1);
+    }
+
+    #[test]
+    fn emboss_empty_sketch() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        assert_eq!(
+            cuboid.emboss(&Sketch::empty(), top_plane(), 0.1.m()),
+            Ok(cuboid)
+        );
+    }
+
+    #[test]
+    fn emboss_self_intersecting_sketch() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        let figure_eight = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 1.m()))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(0.m(), 1.m()))
+            .close();
+        assert_eq!(
+            cuboid.emboss(&figure_eight, top_plane(), 0.1.m()),
+            Err(Error::InvalidWire)
+        );
+    }
+}
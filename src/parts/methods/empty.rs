@@ -1,6 +1,6 @@
-use uom::si::volume::cubic_meter;
+use std::cell::OnceCell;
 
-use crate::Part;
+use crate::{Error, Part};
 
 impl Part {
     /// Construct an empty `Part` which can be used for merging with other parts.
@@ -14,11 +14,19 @@ impl Part {
     /// assert_eq!(part.volume(), Volume::new::<cubic_meter>(0.));
     /// ```
     pub fn empty() -> Self {
-        Self { inner: None }
+        Self {
+            inner: None,
+            properties: OnceCell::new(),
+        }
     }
 
     /// Return true if this `Part` is empty.
     ///
+    /// Besides parts constructed with `Part::empty()`, this includes the results of operations
+    /// that leave no geometry behind, e.g. subtracting a `Part` from itself. The check is
+    /// topological, so even a tiny `Part` is not empty as long as it has a face. This mirrors
+    /// `Sketch::is_empty()`, which is true for sketches without any area.
+    ///
     /// ```rust
     /// use anvil::{Cube, IntoLength, Part};
     ///
@@ -27,6 +35,54 @@ impl Part {
     /// assert!(cube.subtract(&cube).is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.volume().get::<cubic_meter>() < 1e-9
+        self.inner.is_none() || self.face_count() == 0
+    }
+
+    /// Apply an operation to this `Part`, or return `Error::EmptyPart` if it is empty.
+    pub(crate) fn try_non_empty(
+        &self,
+        operation: impl FnOnce(&Self) -> Self,
+    ) -> Result<Self, Error> {
+        match self.inner {
+            Some(_) => Ok(operation(self)),
+            None => Err(Error::EmptyPart),
+        }
+    }
+}
+impl Default for Part {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cuboid, IntoLength};
+
+    #[test]
+    fn empty_is_empty() {
+        assert!(Part::empty().is_empty())
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(Part::default().is_empty())
+    }
+
+    #[test]
+    fn cuboid_is_not_empty() {
+        assert!(!Cuboid::from_dim(1.m(), 2.m(), 3.m()).is_empty())
+    }
+
+    #[test]
+    fn tiny_cuboid_is_not_empty() {
+        assert!(!Cuboid::from_dim(0.5.mm(), 0.5.mm(), 0.5.mm()).is_empty())
+    }
+
+    #[test]
+    fn subtracted_from_itself_is_empty() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        assert!(cuboid.subtract(&cuboid).is_empty())
     }
 }
@@ -0,0 +1,77 @@
+use crate::{Error, Length, Part, Plane, Sketch};
+
+impl Part {
+    /// Return a copy of this `Part` with a `Sketch` cut into it.
+    ///
+    /// The `Sketch` is placed on the `Plane` and extruded by `depth` against the plane normal
+    /// before it is subtracted. The plane should therefore lie on a face of this `Part` with its
+    /// normal pointing outward. If the `Sketch` is empty, the `Part` is returned unchanged, while a
+    /// `Sketch` that can not be extruded, e.g. because it intersects itself, results in an error.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Plane, Rectangle, dir, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+    /// let plane = Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap();
+    /// let label = Rectangle::from_corners(point!(0.5.m(), 0.5.m()), point!(1.5.m(), 1.5.m()));
+    /// assert!(cuboid.engrave(&label, plane, 0.1.m()).unwrap().volume() < cuboid.volume());
+    /// ```
+    pub fn engrave(&self, sketch: &Sketch, plane: Plane, depth: Length) -> Result<Self, Error> {
+        match sketch.extrude(plane, -depth) {
+            Ok(engraving) => Ok(self.subtract(&engraving)),
+            Err(Error::EmptySketch) => Ok(self.clone()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::{Cuboid, Error, IntoLength, Part, Path, Plane, Rectangle, Sketch, dir, point};
+
+    fn top_plane() -> Plane {
+        Plane::new(point!(0.m(), 0.m(), 1.m()), dir!(1, 0, 0), dir!(0, 1, 0)).unwrap()
+    }
+
+    #[test]
+    fn engrave_reduces_volume() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        let label = Rectangle::from_corners(point!(0.5.m(), 0.5.m()), point!(1.5.m(), 1.5.m()));
+        let engraved = cuboid.engrave(&label, top_plane(), 0.1.m()).unwrap();
+        assert_relative_eq!(engraved.volume().value, 4. - 0.1);
+    }
+
+    #[test]
+    fn engrave_empty_sketch() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        assert_eq!(
+            cuboid.engrave(&Sketch::empty(), top_plane(), 0.1.m()),
+            Ok(cuboid)
+        );
+    }
+
+    #[test]
+    fn engrave_empty_part() {
+        let label = Rectangle::from_dim(1.m(), 1.m());
+        assert_eq!(
+            Part::empty().engrave(&label, top_plane(), 0.1.m()),
+            Ok(Part::empty())
+        );
+    }
+
+    #[test]
+    fn engrave_self_intersecting_sketch() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 1.m()));
+        let figure_eight = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 1.m()))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(0.m(), 1.m()))
+            .close();
+        assert_eq!(
+            cuboid.engrave(&figure_eight, top_plane(), 0.1.m()),
+            Err(Error::InvalidWire)
+        );
+    }
+}
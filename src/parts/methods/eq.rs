@@ -4,10 +4,13 @@ impl PartialEq for Part {
     fn eq(&self, other: &Self) -> bool {
         match (&self.inner, &other.inner) {
             (Some(_), Some(_)) => {
-                let intersection = self.intersect(other);
+                let self_volume = self.volume();
+                let other_volume = other.volume();
+                let intersection_volume = self.intersect(other).volume();
 
-                (intersection.volume() - self.volume()).abs() < intersection.volume() * 1e-7
-                    && (intersection.volume() - other.volume()).abs() < intersection.volume() * 1e-7
+                let tolerance = self_volume.max(other_volume) * 1e-7;
+                (intersection_volume - self_volume).abs() <= tolerance
+                    && (intersection_volume - other_volume).abs() <= tolerance
             }
             (Some(_), None) => false,
             (None, Some(_)) => false,
@@ -19,7 +22,7 @@ impl PartialEq for Part {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Cuboid, IntoLength, Sphere};
+    use crate::{Cuboid, IntoLength, Sphere, point};
 
     #[test]
     fn eq_both_none() {
@@ -53,4 +56,28 @@ mod tests {
         let sphere2 = Sphere::from_radius(2.m());
         assert_ne!(sphere1, sphere2)
     }
+
+    #[test]
+    fn neq_non_overlapping_cuboids() {
+        let cuboid1 = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let cuboid2 = cuboid1.move_to(point!(5.m(), 0.m(), 0.m()));
+        assert_ne!(cuboid1, cuboid2);
+        assert_ne!(cuboid2, cuboid1);
+    }
+
+    #[test]
+    fn neq_touching_cuboids() {
+        let cuboid1 = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+        let cuboid2 =
+            Cuboid::from_corners(point!(1.m(), 0.m(), 0.m()), point!(2.m(), 1.m(), 1.m()));
+        assert_ne!(cuboid1, cuboid2)
+    }
+
+    #[test]
+    fn neq_contained_cuboid() {
+        let cuboid1 = Cuboid::from_dim(2.m(), 2.m(), 2.m());
+        let cuboid2 = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_ne!(cuboid1, cuboid2);
+        assert_ne!(cuboid2, cuboid1);
+    }
 }
@@ -0,0 +1,44 @@
+use opencascade_sys::ffi;
+
+use crate::Part;
+
+impl Part {
+    /// Return the number of faces spanned by this `Part`.
+    ///
+    /// Unlike `Part::faces().len()`, this explores the `Part` only once and does not clone it.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, Cylinder, IntoLength, Part};
+    ///
+    /// assert_eq!(Cube::from_size(1.m()).face_count(), 6);
+    /// assert_eq!(Cylinder::from_radius(1.m(), 1.m()).face_count(), 3);
+    /// assert_eq!(Part::empty().face_count(), 0);
+    /// ```
+    pub fn face_count(&self) -> usize {
+        let Some(inner) = &self.inner else {
+            return 0;
+        };
+        let mut explorer = ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+        let mut count = 0;
+        while explorer.More() {
+            count += 1;
+            explorer.pin_mut().Next();
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cube, IntoLength, Part};
+
+    #[test]
+    fn cube() {
+        assert_eq!(Cube::from_size(1.m()).face_count(), 6);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Part::empty().face_count(), 0);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Face, Length, Part, Plane};
+
+impl Part {
+    /// Return the faces of this `Part` that lie in a `Plane`.
+    ///
+    /// A face is kept if its whole underlying surface is within the tolerance of the `Plane`,
+    /// regardless of whether its normal points along or against the normal of the `Plane`.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength, Plane, dir, point};
+    ///
+    /// let cube = Cube::from_size(2.m());
+    /// let top = Plane::from_normal(point!(0.m(), 0.m(), 1.m()), dir!(0, 0, 1)).unwrap();
+    /// assert_eq!(cube.faces_on(top, 1.um()).len(), 1);
+    /// assert_eq!(cube.faces_on(Plane::xy(), 1.um()).len(), 0);
+    /// ```
+    pub fn faces_on(&self, plane: Plane, tolerance: Length) -> Vec<Face> {
+        self.faces()
+            .filter(|face| face.lies_on(plane, tolerance))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cuboid, Cylinder, IntoLength, dir, point};
+
+    #[test]
+    fn cuboid_top_face() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        let top = Plane::from_normal(point!(0.m(), 0.m(), 1.5.m()), dir!(0, 0, 1)).unwrap();
+        assert_eq!(cuboid.faces_on(top, 1.um()).len(), 1);
+    }
+
+    #[test]
+    fn cuboid_bottom_face_with_flipped_plane() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        let bottom = Plane::from_normal(point!(0.m(), 0.m(), -1.5.m()), dir!(0, 0, 1)).unwrap();
+        assert_eq!(cuboid.faces_on(bottom, 1.um()).len(), 1);
+    }
+
+    #[test]
+    fn cylinder_mantle_is_not_on_plane() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let tangent = Plane::from_normal(point!(1.m(), 0.m(), 0.m()), dir!(1, 0, 0)).unwrap();
+        assert!(cylinder.faces_on(tangent, 1.um()).is_empty());
+    }
+
+    #[test]
+    fn empty_part() {
+        assert!(Part::empty().faces_on(Plane::xy(), 1.um()).is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Part, Point};
+
+impl Part {
+    /// Create a two-dimensional grid of instances of the `Part`.
+    ///
+    /// The instances are offset from the original by integer multiples of the two step vectors.
+    /// If either number of instances is zero, the `Part` is returned unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+    /// assert_eq!(
+    ///     cuboid.grid_pattern(point!(2.m(), 0.m(), 0.m()), 2, point!(0.m(), 2.m(), 0.m()), 2),
+    ///     cuboid
+    ///         .add(&cuboid.move_to(point!(2.m(), 0.m(), 0.m())))
+    ///         .add(&cuboid.move_to(point!(0.m(), 2.m(), 0.m())))
+    ///         .add(&cuboid.move_to(point!(2.m(), 2.m(), 0.m())))
+    /// )
+    /// ```
+    pub fn grid_pattern(&self, x_step: Point<3>, nx: u8, y_step: Point<3>, ny: u8) -> Self {
+        if nx == 0 || ny == 0 {
+            return self.clone();
+        }
+        let start = match self.center() {
+            Ok(p) => p,
+            Err(_) => return self.clone(),
+        };
+
+        let mut new_part = self.clone();
+        for ix in 0..nx {
+            for iy in 0..ny {
+                if ix == 0 && iy == 0 {
+                    continue;
+                }
+                let offset = x_step * ix as f64 + y_step * iy as f64;
+                new_part = new_part.add(&self.move_to(start + offset));
+            }
+        }
+        new_part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, IntoLength, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn grid_three_by_two() {
+        let cube = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+        let grid = cube.grid_pattern(
+            point!(1.m(), 0.m(), 0.m()),
+            3,
+            point!(0.m(), 1.m(), 0.m()),
+            2,
+        );
+        assert_relative_eq!(grid.volume().value, 6. * cube.volume().value);
+        assert_eq!(
+            grid,
+            Cuboid::from_corners(point!(0, 0, 0), point!(3.m(), 2.m(), 1.m()))
+        );
+    }
+
+    #[test]
+    fn grid_zero_instances() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let step = point!(2.m(), 0.m(), 0.m());
+        assert_eq!(cube.grid_pattern(step, 0, step, 3), cube);
+        assert_eq!(cube.grid_pattern(step, 3, step, 0), cube);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Angle, Axis, Error, Part, Plane, Sketch};
+
+impl Part {
+    /// Return a copy of this `Part` with a revolved profile removed.
+    ///
+    /// The profile `Sketch` is placed on the plane and revolved around the axis by the given
+    /// angle before it is subtracted from this `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, Cylinder, IntoAngle, IntoLength, Plane, Rectangle, point};
+    ///
+    /// let cylinder = Cylinder::from_radius(2.m(), 2.m());
+    /// let profile = Rectangle::from_corners(point!(1.m(), -1.m()), point!(2.m(), 1.m()));
+    /// assert_eq!(
+    ///     cylinder.groove(&profile, Plane::xz(), Axis::<3>::z(), 360.deg()),
+    ///     Ok(Cylinder::from_radius(1.m(), 2.m()))
+    /// );
+    /// ```
+    pub fn groove(
+        &self,
+        profile: &Sketch,
+        plane: Plane,
+        axis: Axis<3>,
+        angle: Angle,
+    ) -> Result<Self, Error> {
+        let cutter = profile.revolve(plane, axis, angle)?;
+        Ok(self.subtract(&cutter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Cylinder, IntoAngle, IntoLength, Plane, Rectangle, point};
+
+    #[test]
+    fn groove_reduces_cylinder_volume() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let profile = Rectangle::from_corners(point!(0.9.m(), -0.1.m()), point!(1.1.m(), 0.1.m()));
+        let grooved = cylinder
+            .groove(&profile, Plane::xz(), Axis::<3>::z(), 360.deg())
+            .unwrap();
+        assert!(grooved.volume() < cylinder.volume());
+    }
+
+    #[test]
+    fn groove_zero_angle() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let profile = Rectangle::from_corners(point!(0.9.m(), -0.1.m()), point!(1.1.m(), 0.1.m()));
+        assert!(
+            cylinder
+                .groove(&profile, Plane::xz(), Axis::<3>::z(), 0.deg())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+use uom::si::volume::cubic_meter;
+
+use crate::{Error, Length, Part};
+
+impl Part {
+    /// Return a copy of this `Part` with a closed cavity inside that leaves walls of a given
+    /// thickness.
+    ///
+    /// No faces are removed, so the cavity is fully enclosed. If the walls are too thick for the
+    /// `Part`, i.e. the cavity would vanish, an `Error::Offset` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let cube = Cube::from_size(2.m());
+    /// let hollow_cube = cube.hollow(0.5.m()).unwrap();
+    /// assert_relative_eq!(hollow_cube.volume().value, 8. - 1., max_relative = 1e-7);
+    /// ```
+    pub fn hollow(&self, wall: Length) -> Result<Self, Error> {
+        let inner = match &self.inner {
+            Some(inner) => inner,
+            None => return Err(Error::EmptyPart),
+        };
+        if wall <= Length::new::<meter>(0.) {
+            return Err(Error::Offset);
+        }
+
+        let closing_faces = ffi::new_list_of_shape();
+        let mut make_thick_solid = ffi::BRepOffsetAPI_MakeThickSolid_ctor();
+        ffi::MakeThickSolidByJoin(
+            make_thick_solid.pin_mut(),
+            inner,
+            &closing_faces,
+            -wall.get::<meter>(),
+            1e-7,
+        );
+        if !make_thick_solid.IsDone() {
+            return Err(Error::Offset);
+        }
+
+        let hollowed = Self::from_occt(make_thick_solid.pin_mut().Shape());
+        let hollowed_volume = hollowed.volume().get::<cubic_meter>();
+        if hollowed_volume <= 0. || hollowed_volume >= self.volume().get::<cubic_meter>() {
+            return Err(Error::Offset);
+        }
+        Ok(hollowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cube, IntoLength};
+
+    #[test]
+    fn hollow_cube_cavity() {
+        let cube = Cube::from_size(10.mm());
+        let hollow_cube = cube.hollow(1.mm()).unwrap();
+        let cavity = cube.volume() - hollow_cube.volume();
+        assert_relative_eq!(
+            cavity.get::<cubic_meter>(),
+            (8.mm() * 8.mm() * 8.mm()).get::<cubic_meter>(),
+            max_relative = 1e-7
+        );
+    }
+
+    #[test]
+    fn hollow_wall_too_thick() {
+        let cube = Cube::from_size(10.mm());
+        assert_eq!(cube.hollow(6.mm()), Err(Error::Offset));
+    }
+
+    #[test]
+    fn hollow_empty() {
+        assert_eq!(Part::empty().hollow(1.mm()), Err(Error::EmptyPart));
+    }
+}
@@ -21,7 +21,7 @@ impl Part {
                 let mut fuse_operation = ffi::BRepAlgoAPI_Common_ctor(self_inner, other_inner);
                 Self::from_occt(fuse_operation.pin_mut().Shape())
             }
-            _ => Part { inner: None },
+            _ => Part::empty(),
         }
     }
 }
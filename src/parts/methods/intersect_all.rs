@@ -0,0 +1,67 @@
+use crate::Part;
+
+impl Part {
+    /// Return the `Part` that is contained in all of multiple `Part`s.
+    ///
+    /// An empty slice results in an empty `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Part, point};
+    ///
+    /// let cuboid1 = Cuboid::from_corners(point!(0, 0, 0), point!(3.m(), 3.m(), 3.m()));
+    /// let cuboid2 = Cuboid::from_corners(point!(1.m(), 0.m(), 0.m()), point!(4.m(), 3.m(), 3.m()));
+    /// let cuboid3 = Cuboid::from_corners(point!(0.m(), 1.m(), 0.m()), point!(3.m(), 4.m(), 3.m()));
+    /// assert_eq!(
+    ///     Part::intersect_all(&[cuboid1, cuboid2, cuboid3]),
+    ///     Cuboid::from_corners(point!(1.m(), 1.m(), 0.m()), point!(3.m(), 3.m(), 3.m()))
+    /// );
+    /// assert_eq!(Part::intersect_all(&[]), Part::empty());
+    /// ```
+    pub fn intersect_all(parts: &[Self]) -> Self {
+        match parts.split_first() {
+            Some((first, rest)) => rest.iter().fold(first.clone(), |intersection, part| {
+                if intersection.inner.is_none() {
+                    return intersection;
+                }
+                intersection.intersect(part)
+            }),
+            None => Self::empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, IntoLength, Part, Sphere, point};
+
+    #[test]
+    fn intersect_all_matches_chained_intersect() {
+        let a = Cuboid::from_dim(2.m(), 2.m(), 2.m());
+        let b = Sphere::from_radius(1.2.m());
+        let c = Cuboid::from_dim(3.m(), 1.m(), 3.m()).move_to(point!(0.5.m(), 0.m(), 0.m()));
+        assert_eq!(
+            Part::intersect_all(&[a.clone(), b.clone(), c.clone()]),
+            a.intersect(&b).intersect(&c)
+        );
+    }
+
+    #[test]
+    fn intersect_all_single() {
+        let a = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(Part::intersect_all(std::slice::from_ref(&a)), a);
+    }
+
+    #[test]
+    fn intersect_all_with_empty_part() {
+        let a = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(
+            Part::intersect_all(&[a.clone(), Part::empty(), a]),
+            Part::empty()
+        );
+    }
+
+    #[test]
+    fn intersect_all_empty_slice() {
+        assert_eq!(Part::intersect_all(&[]), Part::empty());
+    }
+}
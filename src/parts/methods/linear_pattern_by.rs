@@ -0,0 +1,56 @@
+use crate::{Part, Point};
+
+impl Part {
+    /// Create multiple instances of the `Part`, each offset from the previous one by a step.
+    ///
+    /// The instances are offset from the original by integer multiples of the step vector, with
+    /// the original counting as the first instance. If the number of instances is zero, the `Part`
+    /// is returned unchanged.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+    /// assert_eq!(
+    ///     cuboid.linear_pattern_by(point!(2.m(), 0.m(), 0.m()), 3),
+    ///     cuboid
+    ///         .add(&cuboid.move_to(point!(2.m(), 0.m(), 0.m())))
+    ///         .add(&cuboid.move_to(point!(4.m(), 0.m(), 0.m())))
+    /// )
+    /// ```
+    pub fn linear_pattern_by(&self, step: Point<3>, instances: u8) -> Self {
+        let mut new_part = self.clone();
+        for i in 1..instances {
+            let offset = step * i as f64;
+            new_part = new_part.add(&self.move_by(offset.x(), offset.y(), offset.z()));
+        }
+        new_part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, IntoLength, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn three_instances() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let pattern = cube.linear_pattern_by(point!(2.m(), 0.m(), 0.m()), 3);
+        assert_relative_eq!(pattern.volume().value, 3. * cube.volume().value);
+        assert_relative_eq!(pattern.center().unwrap(), point!(2.m(), 0.m(), 0.m()));
+        assert_eq!(
+            pattern,
+            cube.add(&cube.move_to(point!(2.m(), 0.m(), 0.m())))
+                .add(&cube.move_to(point!(4.m(), 0.m(), 0.m())))
+        );
+    }
+
+    #[test]
+    fn zero_and_one_instances() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let step = point!(2.m(), 0.m(), 0.m());
+        assert_eq!(cube.linear_pattern_by(step, 0), cube);
+        assert_eq!(cube.linear_pattern_by(step, 1), cube);
+    }
+}
@@ -1,18 +1,42 @@
 mod add;
+mod brep;
 mod center;
+mod chamfer_edges;
 mod circular_pattern;
+mod circular_pattern_angle;
 mod clone;
+mod compound;
+mod contains;
+mod convex_hull;
 mod debug;
+mod emboss;
 mod empty;
+mod engrave;
 mod eq;
+mod face_count;
 mod faces;
+mod faces_on;
+mod grid_pattern;
+mod groove;
+mod hollow;
 mod intersect;
+mod intersect_all;
 mod linear_pattern;
+mod linear_pattern_by;
 mod move_by;
 mod move_to;
+mod project;
+mod render_mesh;
 mod rotate_around;
+mod rotate_around_point;
 mod scale;
+mod slices;
 mod step;
 mod stl;
 mod subtract;
+mod subtract_all;
+mod topology;
+mod transformed;
+mod triangulate;
+mod union_all;
 mod volume;
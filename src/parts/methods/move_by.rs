@@ -1,4 +1,6 @@
-use crate::{Length, Part, point};
+use opencascade_sys::ffi;
+
+use crate::{Error, Length, Part, point};
 
 impl Part {
     /// Return a clone of this `Part` moved by a specified amount in each axis.
@@ -16,10 +18,43 @@ impl Part {
     /// )
     /// ```
     pub fn move_by(&self, dx: Length, dy: Length, dz: Length) -> Self {
-        let center = match self.center() {
-            Ok(c) => c,
-            Err(_) => return self.clone(),
-        };
-        self.move_to(center + point!(dx, dy, dz))
+        match &self.inner {
+            Some(inner) => {
+                let mut transform = ffi::new_transform();
+                transform
+                    .pin_mut()
+                    .set_translation_vec(&point!(dx, dy, dz).to_occt_vec());
+                let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
+                Self::from_occt(operation.pin_mut().Shape())
+            }
+            None => Self::empty(),
+        }
+    }
+
+    /// Fallible version of `Part::move_by`.
+    pub fn try_move_by(&self, dx: Length, dy: Length, dz: Length) -> Result<Self, Error> {
+        self.try_non_empty(|part| part.move_by(dx, dy, dz))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Error, IntoLength, Part, point};
+
+    #[test]
+    fn move_by_offsets_corners() {
+        let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 2.m(), 3.m()));
+        assert_eq!(
+            cuboid.move_by(1.m(), -1.m(), 2.m()),
+            Cuboid::from_corners(point!(1.m(), -1.m(), 2.m()), point!(2.m(), 1.m(), 5.m()))
+        );
+    }
+
+    #[test]
+    fn try_move_by_empty() {
+        assert_eq!(
+            Part::empty().try_move_by(1.m(), 2.m(), 3.m()),
+            Err(Error::EmptyPart)
+        );
     }
 }
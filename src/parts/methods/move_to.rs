@@ -1,6 +1,6 @@
 use opencascade_sys::ffi;
 
-use crate::{Part, Point};
+use crate::{Error, Part, Point};
 
 impl Part {
     /// Return a clone of this `Part` with the center moved to a specified point.
@@ -22,9 +22,14 @@ impl Part {
                 let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
                 Self::from_occt(operation.pin_mut().Shape())
             }
-            None => Self { inner: None },
+            None => Self::empty(),
         }
     }
+
+    /// Fallible version of `Part::move_to`.
+    pub fn try_move_to(&self, loc: Point<3>) -> Result<Self, Error> {
+        self.try_non_empty(|part| part.move_to(loc))
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -60,4 +65,12 @@ mod tests {
             Cuboid::from_dim(2.m(), 1.m(), 1.m())
         )
     }
+
+    #[test]
+    fn try_move_to_empty() {
+        assert_eq!(
+            Part::empty().try_move_to(point!(1.m(), 2.m(), 3.m())),
+            Err(Error::EmptyPart)
+        );
+    }
 }
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use uom::si::length::meter;
+
+use crate::{Error, IndexedMesh, IntoLength, MeshOptions, Part, Path, Plane, Point, Sketch};
+
+impl Part {
+    /// Return the silhouette of this `Part` when looking at it along the normal of a `Plane`.
+    ///
+    /// The silhouette is built from the outlines of the surfaces facing the viewer in a coarse mesh
+    /// of the `Part` and is expressed in the coordinates of the `Plane`.
+    ///
+    /// This is an approximation by design: instead of exact hidden line removal, the projection
+    /// works on the mesh, so curved outlines like those of a cylinder or sphere come out as
+    /// polylines with the resolution of that coarse mesh. Only outlines made of straight edges,
+    /// like those of a `Cuboid`, are reproduced exactly.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Plane, Rectangle};
+    ///
+    /// let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+    /// assert_eq!(
+    ///     cuboid.project(Plane::xy()),
+    ///     Ok(Rectangle::from_dim(1.m(), 2.m()))
+    /// );
+    /// ```
+    pub fn project(&self, plane: Plane) -> Result<Sketch, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+
+        let mesh = IndexedMesh::from_render_mesh(&self.render_mesh(MeshOptions::COARSE)?);
+        let to_plane = |point: &Point<3>| -> [f64; 2] {
+            let offset = *point - plane.origin();
+            let offset = [
+                offset.x().get::<meter>(),
+                offset.y().get::<meter>(),
+                offset.z().get::<meter>(),
+            ];
+            let (x, y) = (plane.x(), plane.y());
+            [
+                offset[0] * x.x() + offset[1] * x.y() + offset[2] * x.z(),
+                offset[0] * y.x() + offset[1] * y.y() + offset[2] * y.z(),
+            ]
+        };
+        let points: Vec<[f64; 2]> = mesh.points().iter().map(to_plane).collect();
+
+        let mut silhouette = Sketch::empty();
+        for region in front_outlines(&points, mesh.indices()) {
+            let mut region_sketch = Sketch::empty();
+            for (area, outline) in region {
+                let mut outline = outline
+                    .into_iter()
+                    .map(|[x, y]| Point::<2>::new([x.m(), y.m()]));
+                let start = outline.next().expect("outlines have at least three points");
+                let outline = outline
+                    .fold(Path::at(start), |path, point| path.line_to(point))
+                    .close();
+                region_sketch = if area > 0. {
+                    region_sketch.add(&outline)
+                } else {
+                    region_sketch.subtract(&outline)
+                };
+            }
+            silhouette = silhouette.add(&region_sketch);
+        }
+        Ok(silhouette)
+    }
+}
+
+/// A closed outline in plane coordinates together with its signed area.
+type Outline = (f64, Vec<[f64; 2]>);
+
+/// Return the outlines of the connected regions of triangles facing the viewer, i.e. those that
+/// run counterclockwise after projecting the points.
+///
+/// Every region consists of its outlines together with their signed area, sorted from largest to
+/// smallest. Outer outlines have a positive area, holes a negative one.
+fn front_outlines(points: &[[f64; 2]], indices: &[[usize; 3]]) -> Vec<Vec<Outline>> {
+    let signed_area = |outline: &[[f64; 2]]| -> f64 {
+        (0..outline.len())
+            .map(|i| {
+                let (a, b) = (outline[i], outline[(i + 1) % outline.len()]);
+                a[0] * b[1] - b[0] * a[1]
+            })
+            .sum::<f64>()
+            / 2.
+    };
+    // triangles seen from behind are covered by the ones facing the viewer
+    let front: Vec<[usize; 3]> = indices
+        .iter()
+        .filter(|triangle| signed_area(&triangle.map(|index| points[index])) > 1e-12)
+        .copied()
+        .collect();
+
+    let mut edge_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (triangle_index, triangle) in front.iter().enumerate() {
+        for i in 0..3 {
+            let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+            edge_triangles
+                .entry((a.min(b), a.max(b)))
+                .or_default()
+                .push(triangle_index);
+        }
+    }
+
+    // triangles sharing an edge belong to the same region
+    let mut parents: Vec<usize> = (0..front.len()).collect();
+    for triangles in edge_triangles.values() {
+        for other in &triangles[1..] {
+            let (a, b) = (root(&mut parents, triangles[0]), root(&mut parents, *other));
+            parents[a] = b;
+        }
+    }
+
+    // edges that are only used by a single triangle form the outlines, keeping the region on
+    // their left
+    let mut outgoing: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (&(low, high), triangles) in &edge_triangles {
+        if let [triangle_index] = triangles[..] {
+            let triangle = front[triangle_index];
+            let forward = (0..3).any(|i| triangle[i] == low && triangle[(i + 1) % 3] == high);
+            let (start, end) = if forward { (low, high) } else { (high, low) };
+            outgoing
+                .entry((root(&mut parents, triangle_index), start))
+                .or_default()
+                .push(end);
+        }
+    }
+
+    let mut regions: HashMap<usize, Vec<Outline>> = HashMap::new();
+    while let Some(&(region, start)) = outgoing.keys().next() {
+        let mut outline = vec![];
+        let mut current = start;
+        while let Some(ends) = outgoing.get_mut(&(region, current)) {
+            let next = ends.pop().expect("empty entries are removed");
+            if ends.is_empty() {
+                outgoing.remove(&(region, current));
+            }
+            outline.push(points[current]);
+            current = next;
+            if current == start {
+                break;
+            }
+        }
+        if outline.len() >= 3 {
+            regions
+                .entry(region)
+                .or_default()
+                .push((signed_area(&outline), outline));
+        }
+    }
+
+    regions
+        .into_values()
+        .map(|mut outlines| {
+            outlines.sort_by(|a, b| b.0.abs().total_cmp(&a.0.abs()));
+            outlines
+        })
+        .collect()
+}
+
+/// Return the representative of the set containing an index in a disjoint-set forest.
+fn root(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cylinder, Rectangle};
+
+    #[test]
+    fn project_cylinder_along_axis() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let silhouette = cylinder.project(Plane::xy()).unwrap();
+        assert_relative_eq!(
+            silhouette.area().value,
+            std::f64::consts::PI,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(silhouette.center().unwrap(), Point::<2>::origin());
+    }
+
+    #[test]
+    fn project_cylinder_sideways() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let silhouette = cylinder.project(Plane::xz()).unwrap();
+        assert_relative_eq!(silhouette.area().value, 4., max_relative = 1e-3);
+        let rectangle = Rectangle::from_dim(2.m(), 2.m());
+        assert_relative_eq!(
+            silhouette.intersect(&rectangle).area().value,
+            silhouette.area().value
+        );
+    }
+
+    fn unit_cube(offset: f64) -> (Vec<[f64; 2]>, Vec<[usize; 3]>) {
+        let points = (0..8)
+            .map(|i| [(i & 1) as f64 + offset, (i >> 1 & 1) as f64])
+            .collect();
+        let quads = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+        ];
+        let indices = quads
+            .iter()
+            .flat_map(|[a, b, c, d]| [[*a, *b, *c], [*a, *c, *d]])
+            .collect();
+        (points, indices)
+    }
+
+    #[test]
+    fn front_outlines_of_cube() {
+        let (points, indices) = unit_cube(0.);
+        let regions = front_outlines(&points, &indices);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].len(), 1);
+        assert_relative_eq!(regions[0][0].0, 1.);
+        assert_eq!(regions[0][0].1.len(), 4);
+    }
+
+    #[test]
+    fn front_outlines_of_separate_cubes() {
+        let (mut points, mut indices) = unit_cube(0.);
+        let (other_points, other_indices) = unit_cube(3.);
+        indices.extend(
+            other_indices
+                .iter()
+                .map(|triangle| triangle.map(|index| index + points.len())),
+        );
+        points.extend(other_points);
+
+        let regions = front_outlines(&points, &indices);
+        assert_eq!(regions.len(), 2);
+        for region in regions {
+            assert_relative_eq!(region[0].0, 1.);
+        }
+    }
+
+    #[test]
+    fn project_empty() {
+        assert_eq!(Part::empty().project(Plane::xy()), Err(Error::EmptyPart));
+    }
+}
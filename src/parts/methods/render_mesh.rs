@@ -0,0 +1,57 @@
+use crate::{Error, MeshOptions, Part, RenderMesh};
+
+impl Part {
+    /// Return the triangulated surface of this `Part` as a `RenderMesh`.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, MeshOptions, Sphere};
+    ///
+    /// let sphere = Sphere::from_radius(1.m());
+    /// let coarse = sphere.render_mesh(MeshOptions::default().with_tolerance(10.cm())).unwrap();
+    /// let fine = sphere.render_mesh(MeshOptions::default().with_tolerance(1.mm())).unwrap();
+    /// assert!(fine.indices().len() > coarse.indices().len());
+    /// ```
+    pub fn render_mesh(&self, options: MeshOptions) -> Result<RenderMesh, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+        RenderMesh::try_from((self.clone(), options.tolerance, options.angular_tolerance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoLength, Sphere};
+
+    #[test]
+    fn default_options_match_try_from() {
+        let sphere = Sphere::from_radius(1.m());
+        assert_eq!(
+            sphere.render_mesh(MeshOptions::default()),
+            RenderMesh::try_from(sphere)
+        );
+    }
+
+    #[test]
+    fn tolerances_change_triangle_count() {
+        let sphere = Sphere::from_radius(1.m());
+        let coarse = sphere
+            .render_mesh(MeshOptions::default().with_tolerance(10.cm()))
+            .unwrap();
+        let fine = sphere
+            .render_mesh(MeshOptions::default().with_tolerance(1.mm()))
+            .unwrap();
+        assert!(fine.indices().len() > coarse.indices().len());
+    }
+
+    #[test]
+    fn empty_part() {
+        assert_eq!(
+            Part::empty().render_mesh(MeshOptions::default()),
+            Err(Error::EmptyPart)
+        );
+    }
+}
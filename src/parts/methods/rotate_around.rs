@@ -1,7 +1,7 @@
 use opencascade_sys::ffi;
 use uom::si::angle::radian;
 
-use crate::{Angle, Axis, Part};
+use crate::{Angle, Axis, Error, Part};
 
 impl Part {
     /// Return a clone of this `Part` rotated around an `Axis::<3>`.
@@ -27,7 +27,25 @@ impl Part {
                 let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
                 Self::from_occt(operation.pin_mut().Shape())
             }
-            None => Self { inner: None },
+            None => Self::empty(),
         }
     }
+
+    /// Fallible version of `Part::rotate_around`.
+    pub fn try_rotate_around(&self, axis: Axis<3>, angle: Angle) -> Result<Self, Error> {
+        self.try_non_empty(|part| part.rotate_around(axis, angle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Error, IntoAngle, Part};
+
+    #[test]
+    fn try_rotate_around_empty() {
+        assert_eq!(
+            Part::empty().try_rotate_around(Axis::<3>::z(), 90.deg()),
+            Err(Error::EmptyPart)
+        );
+    }
 }
@@ -0,0 +1,76 @@
+use crate::{Angle, Axis, Dir, Error, Part, Point};
+
+impl Part {
+    /// Return a clone of this `Part` rotated around an axis through a `Point`.
+    ///
+    /// This is equivalent to `Part::rotate_around` with an `Axis::<3>` constructed from the point
+    /// and the normal. For positive angles, the right-hand-rule applies for the direction of
+    /// rotation around the normal.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoAngle, IntoLength, dir, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(2.m(), 1.m(), 1.m()).move_to(point!(3.m(), 0.m(), 0.m()));
+    /// assert_eq!(
+    ///     cuboid.rotate_around_point(point!(3.m(), 0.m(), 0.m()), dir!(0, 0, 1), 90.deg()),
+    ///     Cuboid::from_dim(1.m(), 2.m(), 1.m()).move_to(point!(3.m(), 0.m(), 0.m()))
+    /// )
+    /// ```
+    pub fn rotate_around_point(&self, point: Point<3>, normal: Dir<3>, angle: Angle) -> Self {
+        self.rotate_around(Axis::<3>::new(point, normal), angle)
+    }
+
+    /// Fallible version of `Part::rotate_around_point`.
+    pub fn try_rotate_around_point(
+        &self,
+        point: Point<3>,
+        normal: Dir<3>,
+        angle: Angle,
+    ) -> Result<Self, Error> {
+        self.try_non_empty(|part| part.rotate_around_point(point, normal, angle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Cuboid, Error, IntoAngle, IntoLength, Part, dir, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn swap_extents_around_own_center() {
+        let center = point!(1.m(), 2.m(), 3.m());
+        let cuboid = Cuboid::from_dim(4.m(), 1.m(), 1.m()).move_to(center);
+        let rotated = cuboid.rotate_around_point(center, dir!(0, 0, 1), 90.deg());
+        assert_eq!(
+            rotated,
+            Cuboid::from_dim(1.m(), 4.m(), 1.m()).move_to(center)
+        );
+        assert_relative_eq!(rotated.center().unwrap(), center);
+    }
+
+    #[test]
+    fn same_as_rotate_around_axis() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        let point = point!(1.m(), 0.m(), 0.m());
+        assert_eq!(
+            cuboid.rotate_around_point(point, dir!(0, 1, 0), 45.deg()),
+            cuboid.rotate_around(Axis::<3>::new(point, dir!(0, 1, 0)), 45.deg())
+        );
+    }
+
+    #[test]
+    fn empty_part() {
+        assert_eq!(
+            Part::empty().rotate_around_point(point!(0, 0, 0), dir!(0, 0, 1), 90.deg()),
+            Part::empty()
+        );
+    }
+
+    #[test]
+    fn try_rotate_around_point_empty() {
+        assert_eq!(
+            Part::empty().try_rotate_around_point(point!(0, 0, 0), dir!(0, 0, 1), 90.deg()),
+            Err(Error::EmptyPart)
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use opencascade_sys::ffi;
 
-use crate::Part;
+use crate::{Error, Part, Point, Transform};
 
 impl Part {
     /// Return a clone of this `Part` with the size scaled by a factor.
     ///
+    /// A factor of zero collapses the `Part` into a point and results in an empty `Part`.
+    ///
     /// ```rust
     /// use anvil::{Cuboid, IntoLength};
     ///
@@ -16,7 +18,7 @@ impl Part {
     /// ```
     pub fn scale(&self, factor: f64) -> Self {
         match &self.inner {
-            Some(inner) => {
+            Some(inner) if !is_singular(factor) => {
                 let mut transform = ffi::new_transform();
                 transform.pin_mut().SetScale(
                     &self.center().expect("shape is not empty").to_occt_point(),
@@ -25,7 +27,58 @@ impl Part {
                 let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
                 Self::from_occt(operation.pin_mut().Shape())
             }
-            None => Self { inner: None },
+            _ => Self::empty(),
+        }
+    }
+
+    /// Fallible version of `Part::scale`.
+    ///
+    /// Returns `Error::SingularTransform` for a factor of zero.
+    pub fn try_scale(&self, factor: f64) -> Result<Self, Error> {
+        if is_singular(factor) {
+            return Err(Error::SingularTransform);
         }
+        self.try_non_empty(|part| part.scale(factor))
+    }
+}
+
+/// Return true if scaling by a factor is rejected by OCCT.
+fn is_singular(factor: f64) -> bool {
+    Transform::scale(Point::<3>::origin(), factor).is_singular()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Error, IntoLength, Part, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scale_cube_volume() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_relative_eq!(cube.scale(2.).volume().value, 8. * cube.volume().value);
+    }
+
+    #[test]
+    fn scale_keeps_center() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m()).move_to(point!(1.m(), 2.m(), 3.m()));
+        assert_eq!(cube.scale(2.).center(), Ok(point!(1.m(), 2.m(), 3.m())));
+    }
+
+    #[test]
+    fn scale_by_one_is_identity() {
+        let cube = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 2.m(), 3.m()));
+        assert_eq!(cube.scale(1.), cube);
+    }
+
+    #[test]
+    fn try_scale_empty() {
+        assert_eq!(Part::empty().try_scale(2.), Err(Error::EmptyPart));
+    }
+
+    #[test]
+    fn scale_by_zero() {
+        let cube = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(cube.scale(0.), Part::empty());
+        assert_eq!(cube.try_scale(0.), Err(Error::SingularTransform));
     }
 }
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use uom::si::length::meter;
+
+use crate::{IndexedMesh, IntoLength, Length, MeshOptions, Part, Path, Plane, Point, Sketch};
+
+impl Part {
+    /// Return the cross-sections of this `Part` with evenly spaced planes parallel to a `Plane`.
+    ///
+    /// The sections are taken at every multiple of the spacing along the normal of the `Plane`
+    /// that cuts through the `Part`, ordered along the normal. Each `Sketch` is expressed in the
+    /// coordinates of the `Plane`. As the sections are computed from a coarse mesh of the `Part`,
+    /// curved outlines are approximated by straight lines. An empty `Part` or a spacing that is
+    /// not positive results in no sections.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Plane, Rectangle};
+    ///
+    /// let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+    /// let slices = cuboid.slices(Plane::xy(), 1.m());
+    /// assert_eq!(slices.len(), 3);
+    /// assert_eq!(slices[0], Rectangle::from_dim(1.m(), 2.m()));
+    /// ```
+    pub fn slices(&self, plane: Plane, spacing: Length) -> Vec<Sketch> {
+        let spacing = spacing.get::<meter>();
+        if spacing <= 0. {
+            return vec![];
+        }
+        // faces are triangulated separately, so their shared points need to be merged for the
+        // outlines to continue across face borders
+        let Ok(mesh) = self.render_mesh(MeshOptions::COARSE) else {
+            return vec![];
+        };
+        let mesh = IndexedMesh::from_render_mesh(&mesh);
+
+        let to_plane = |point: Point<3>| -> [f64; 3] {
+            let offset = point - plane.origin();
+            let offset = [
+                offset.x().get::<meter>(),
+                offset.y().get::<meter>(),
+                offset.z().get::<meter>(),
+            ];
+            let (x, y, n) = (plane.x(), plane.y(), plane.normal());
+            [
+                offset[0] * x.x() + offset[1] * x.y() + offset[2] * x.z(),
+                offset[0] * y.x() + offset[1] * y.y() + offset[2] * y.z(),
+                offset[0] * n.x() + offset[1] * n.y() + offset[2] * n.z(),
+            ]
+        };
+        let points: Vec<[f64; 3]> = mesh.points().iter().map(|p| to_plane(*p)).collect();
+
+        let (min, max) = points
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |acc, p| {
+                (acc.0.min(p[2]), acc.1.max(p[2]))
+            });
+        // sections through the outermost faces would be degenerate, so they are skipped
+        let first = (min / spacing + 1e-9).floor() as i64 + 1;
+        let last = (max / spacing - 1e-9).ceil() as i64 - 1;
+
+        (first..=last)
+            .map(|level| section(&points, mesh.indices(), level as f64 * spacing))
+            .collect()
+    }
+}
+
+/// Return the cross-section of a triangle mesh with the plane at the given height.
+///
+/// The points are given in plane coordinates, with the height along the plane normal last.
+fn section(points: &[[f64; 3]], indices: &[[usize; 3]], height: f64) -> Sketch {
+    let mut sketch = Sketch::empty();
+    for (area, outline) in section_loops(points, indices, height) {
+        let mut outline = outline
+            .into_iter()
+            .map(|[x, y]| Point::<2>::new([x.m(), y.m()]));
+        let start = outline.next().expect("loops have at least three points");
+        let loop_sketch = outline
+            .fold(Path::at(start), |path, point| path.line_to(point))
+            .close();
+        sketch = if area > 0. {
+            sketch.add(&loop_sketch)
+        } else {
+            sketch.subtract(&loop_sketch)
+        };
+    }
+    sketch
+}
+
+/// Return the closed outlines in which a triangle mesh intersects the plane at the given height,
+/// together with their signed area.
+///
+/// Outer outlines run counterclockwise and have a positive area, holes run clockwise. The
+/// outlines are sorted from largest to smallest, so applying them in order handles holes and
+/// islands within them.
+fn section_loops(
+    points: &[[f64; 3]],
+    indices: &[[usize; 3]],
+    height: f64,
+) -> Vec<(f64, Vec<[f64; 2]>)> {
+    let is_above = |index: usize| points[index][2] >= height;
+    let crossing = |edge: (usize, usize)| -> [f64; 2] {
+        let (a, b) = (points[edge.0], points[edge.1]);
+        let t = (height - a[2]) / (b[2] - a[2]);
+        [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])]
+    };
+    let edge_key = |a: usize, b: usize| (a.min(b), a.max(b));
+
+    // Every triangle crossing the plane contributes one segment, identified by the mesh edges it
+    // starts and ends on. Segments are oriented so that the material lies to their left.
+    let mut segments = HashMap::new();
+    for &[a, b, c] in indices {
+        let corners = [a, b, c];
+        let crossed: Vec<(usize, usize)> = (0..3)
+            .map(|i| (corners[i], corners[(i + 1) % 3]))
+            .filter(|&(start, end)| is_above(start) != is_above(end))
+            .collect();
+        if let [first, second] = crossed[..] {
+            // for counterclockwise triangles, the segment starts on the edge descending through
+            // the plane
+            let (start, end) = if is_above(first.0) {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            segments.insert(edge_key(start.0, start.1), edge_key(end.0, end.1));
+        }
+    }
+
+    let mut loops = vec![];
+    while let Some(&start) = segments.keys().next() {
+        let mut outline = vec![crossing(start)];
+        let mut current = start;
+        while let Some(next) = segments.remove(&current) {
+            if next == start {
+                break;
+            }
+            let point = crossing(next);
+            let previous = outline[outline.len() - 1];
+            if (point[0] - previous[0]).hypot(point[1] - previous[1]) > 1e-12 {
+                outline.push(point);
+            }
+            current = next;
+        }
+        if outline.len() >= 3 {
+            loops.push(outline);
+        }
+    }
+
+    let signed_area = |outline: &Vec<[f64; 2]>| -> f64 {
+        (0..outline.len())
+            .map(|i| {
+                let (a, b) = (outline[i], outline[(i + 1) % outline.len()]);
+                a[0] * b[1] - b[0] * a[1]
+            })
+            .sum::<f64>()
+            / 2.
+    };
+    let mut loops: Vec<(f64, Vec<[f64; 2]>)> = loops
+        .into_iter()
+        .map(|outline| (signed_area(&outline), outline))
+        .collect();
+    loops.sort_by(|a, b| b.0.abs().total_cmp(&a.0.abs()));
+    loops
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cube, Cylinder, Rectangle};
+
+    #[test]
+    fn slice_cylinder_along_axis() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let slices = cylinder.slices(Plane::xy(), 0.25.m());
+        assert_eq!(slices.len(), 7);
+        for slice in slices {
+            assert_relative_eq!(
+                slice.area().value,
+                std::f64::consts::PI,
+                max_relative = 1e-2
+            );
+        }
+    }
+
+    #[test]
+    fn slice_hollow_cube() {
+        let hollow_cube = Cube::from_size(2.m()).subtract(&Cylinder::from_radius(0.5.m(), 3.m()));
+        let slices = hollow_cube.slices(Plane::xy(), 1.m());
+        assert_eq!(slices.len(), 1);
+        assert_relative_eq!(
+            slices[0].area().value,
+            4. - std::f64::consts::PI * 0.25,
+            max_relative = 1e-2
+        );
+    }
+
+    #[test]
+    fn slice_sideways() {
+        let cube = Cube::from_size(2.m());
+        let slices = cube.slices(Plane::yz(), 0.5.m());
+        assert_eq!(slices.len(), 3);
+        assert_eq!(slices[1], Rectangle::from_dim(2.m(), 2.m()));
+    }
+
+    #[test]
+    fn section_of_cube_is_single_loop() {
+        let mesh = IndexedMesh::from_render_mesh(
+            &Cube::from_size(2.m())
+                .render_mesh(MeshOptions::COARSE)
+                .unwrap(),
+        );
+        let points: Vec<[f64; 3]> = mesh
+            .points()
+            .iter()
+            .map(|point| point.0.map(|coordinate| coordinate.get::<meter>()))
+            .collect();
+
+        let loops = section_loops(&points, mesh.indices(), 0.);
+        assert_eq!(loops.len(), 1);
+        assert_relative_eq!(loops[0].0, 4.);
+        assert!(loops[0].1.len() >= 4);
+    }
+
+    #[test]
+    fn section_loops_of_welded_cube() {
+        let points: Vec<[f64; 3]> = (0..8)
+            .map(|i| [(i & 1) as f64, (i >> 1 & 1) as f64, (i >> 2 & 1) as f64])
+            .collect();
+        let quads = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+        ];
+        let indices: Vec<[usize; 3]> = quads
+            .iter()
+            .flat_map(|[a, b, c, d]| [[*a, *b, *c], [*a, *c, *d]])
+            .collect();
+
+        let loops = section_loops(&points, &indices, 0.5);
+        assert_eq!(loops.len(), 1);
+        assert_relative_eq!(loops[0].0, 1.);
+    }
+
+    #[test]
+    fn slice_empty() {
+        assert!(Part::empty().slices(Plane::xy(), 1.m()).is_empty());
+    }
+
+    #[test]
+    fn slice_zero_spacing() {
+        assert!(Cube::from_size(1.m()).slices(Plane::xy(), 0.m()).is_empty());
+    }
+}
@@ -41,6 +41,20 @@ impl Part {
             None => Err(Error::EmptyPart),
         }
     }
+
+    /// Write multiple `Part`s into a single file in the STL format.
+    ///
+    /// The `Part`s are combined without fusing them, so the faces of every `Part` end up in the
+    /// file at their current position. Empty `Part`s are skipped and writing only empty `Part`s
+    /// results in an `Error::EmptyPart`.
+    pub fn write_stl_all(
+        parts: &[Self],
+        path: impl AsRef<Path>,
+        tolerance: f64,
+    ) -> Result<(), Error> {
+        Self::compound(parts).write_stl_with_tolerance(path, tolerance)
+    }
+
     /// Return the STL lines that describe this `Part`.
     pub fn stl(&self) -> Result<Vec<String>, Error> {
         match &self.inner {
@@ -62,3 +76,41 @@ impl Part {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cube, IndexedMesh, IntoLength, point};
+
+    fn read_mesh(parts: &[Part]) -> IndexedMesh {
+        let temp_file = NamedTempFile::new().expect("could not create tempfile");
+        Part::write_stl_all(parts, temp_file.path(), 0.0001).unwrap();
+        IndexedMesh::read_stl(temp_file.path()).unwrap()
+    }
+
+    #[test]
+    fn write_stl_all_two_cubes() {
+        let cube1 = Cube::from_size(1.m());
+        let cube2 = cube1.move_to(point!(3.m(), 0.m(), 0.m()));
+
+        let mesh = read_mesh(&[cube1.clone(), cube2.clone()]);
+        assert_eq!(
+            mesh.indices().len(),
+            read_mesh(&[cube1]).indices().len() + read_mesh(&[cube2]).indices().len()
+        );
+
+        let x_values: Vec<f64> = mesh.points().iter().map(|p| p.x().value).collect();
+        let x_min = x_values.iter().cloned().fold(f64::INFINITY, f64::min);
+        let x_max = x_values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!((x_min, x_max), (-0.5, 3.5));
+    }
+
+    #[test]
+    fn write_stl_all_empty() {
+        let temp_file = NamedTempFile::new().expect("could not create tempfile");
+        assert!(matches!(
+            Part::write_stl_all(&[Part::empty()], temp_file.path(), 0.0001),
+            Err(Error::EmptyPart)
+        ));
+    }
+}
@@ -22,7 +22,7 @@ impl Part {
                 Self::from_occt(fuse_operation.pin_mut().Shape())
             }
             (Some(_), None) => self.clone(),
-            (None, _) => Part { inner: None },
+            (None, _) => Part::empty(),
         }
     }
 }
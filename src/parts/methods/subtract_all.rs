@@ -0,0 +1,52 @@
+use crate::Part;
+
+impl Part {
+    /// Return a copy of this `Part` with the intersections of multiple others removed.
+    ///
+    /// The other parts are merged before they are removed from this one, which is faster than
+    /// chaining `Part::subtract()` calls for many parts.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(3.m(), 1.m(), 1.m()));
+    /// let cutter1 = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+    /// let cutter2 = Cuboid::from_corners(point!(2.m(), 0.m(), 0.m()), point!(3.m(), 1.m(), 1.m()));
+    /// assert_eq!(
+    ///     cuboid.subtract_all(&[cutter1, cutter2]),
+    ///     Cuboid::from_corners(point!(1.m(), 0.m(), 0.m()), point!(2.m(), 1.m(), 1.m()))
+    /// );
+    /// ```
+    pub fn subtract_all(&self, others: &[Self]) -> Self {
+        self.subtract(&Self::union_all(others))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Cylinder, IntoLength, Part, point};
+
+    #[test]
+    fn subtract_all_matches_chained_subtract() {
+        let part = Cuboid::from_dim(4.m(), 4.m(), 1.m());
+        let hole1 = Cylinder::from_radius(0.5.m(), 2.m()).move_to(point!(1.m(), 1.m(), 0.m()));
+        let hole2 = Cylinder::from_radius(0.5.m(), 2.m()).move_to(point!(-1.m(), 1.m(), 0.m()));
+        let hole3 = Cylinder::from_radius(0.5.m(), 2.m()).move_to(point!(0.m(), -1.m(), 0.m()));
+        assert_eq!(
+            part.subtract_all(&[hole1.clone(), hole2.clone(), hole3.clone()]),
+            part.subtract(&hole1).subtract(&hole2).subtract(&hole3)
+        );
+    }
+
+    #[test]
+    fn subtract_all_empty_slice() {
+        let part = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(part.subtract_all(&[]), part);
+    }
+
+    #[test]
+    fn subtract_all_from_empty() {
+        let part = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(Part::empty().subtract_all(&[part]), Part::empty());
+    }
+}
@@ -0,0 +1,72 @@
+use opencascade_sys::ffi;
+
+use crate::{Part, Topology};
+
+impl Part {
+    /// Return the number of solids, shells, faces, edges and vertices of this `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, Cylinder, IntoLength, Part, Topology};
+    ///
+    /// let cube = Cube::from_size(2.m());
+    /// assert_eq!(cube.topology().edges, 12);
+    ///
+    /// let drilled_cube = cube.subtract(&Cylinder::from_radius(0.5.m(), 3.m()));
+    /// assert!(drilled_cube.topology().edges > 12);
+    ///
+    /// assert_eq!(Part::empty().topology(), Topology::default());
+    /// ```
+    pub fn topology(&self) -> Topology {
+        let Some(inner) = &self.inner else {
+            return Topology::default();
+        };
+        let count = |shape_type| {
+            let mut shape_map = ffi::TopTools_IndexedMapOfShape_ctor();
+            ffi::TopExp_MapShapes(inner, shape_type, shape_map.pin_mut());
+            shape_map.Extent() as usize
+        };
+
+        Topology {
+            solids: count(ffi::TopAbs_ShapeEnum::TopAbs_SOLID),
+            shells: count(ffi::TopAbs_ShapeEnum::TopAbs_SHELL),
+            faces: count(ffi::TopAbs_ShapeEnum::TopAbs_FACE),
+            edges: count(ffi::TopAbs_ShapeEnum::TopAbs_EDGE),
+            vertices: count(ffi::TopAbs_ShapeEnum::TopAbs_VERTEX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cube, Cylinder, IntoLength};
+
+    #[test]
+    fn cube() {
+        assert_eq!(
+            Cube::from_size(1.m()).topology(),
+            Topology {
+                solids: 1,
+                shells: 1,
+                faces: 6,
+                edges: 12,
+                vertices: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn cube_with_through_hole() {
+        let cube = Cube::from_size(2.m());
+        let drilled_cube = cube.subtract(&Cylinder::from_radius(0.5.m(), 3.m()));
+        let topology = drilled_cube.topology();
+        assert_eq!(topology.solids, 1);
+        assert!(topology.faces > cube.topology().faces);
+        assert!(topology.edges > cube.topology().edges);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Part::empty().topology(), Topology::default());
+    }
+}
@@ -0,0 +1,104 @@
+use opencascade_sys::ffi;
+
+use crate::{Error, Part, Transform};
+
+impl Part {
+    /// Return a clone of this `Part` with a `Transform` applied.
+    ///
+    /// A singular `Transform`, e.g. a scaling by zero, collapses the `Part` and results in an empty
+    /// `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Axis, Cuboid, IntoAngle, IntoLength, Transform, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+    /// let transform = Transform::translation(point!(1.m(), 0.m(), 0.m()))
+    ///     .then(Transform::rotation(Axis::<3>::z(), 90.deg()));
+    /// assert_eq!(
+    ///     cuboid.transformed(transform),
+    ///     Cuboid::from_corners(point!(0.m(), 1.m(), 0.m()), point!(-1.m(), 2.m(), 1.m()))
+    /// )
+    /// ```
+    pub fn transformed(&self, transform: Transform) -> Self {
+        match (&self.inner, transform.to_occt()) {
+            (Some(inner), Some(transform)) => {
+                let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, true);
+                Self::from_occt(operation.pin_mut().Shape())
+            }
+            _ => Self::empty(),
+        }
+    }
+
+    /// Fallible version of `Part::transformed`.
+    ///
+    /// Returns `Error::SingularTransform` if the `Transform` would collapse the `Part`.
+    pub fn try_transformed(&self, transform: Transform) -> Result<Self, Error> {
+        if transform.is_singular() {
+            return Err(Error::SingularTransform);
+        }
+        self.try_non_empty(|part| part.transformed(transform))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Axis, Cuboid, Error, IntoAngle, IntoLength, Part, Plane, Transform, dir, point};
+
+    #[test]
+    fn composed_matches_sequential() {
+        let part = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 2.m(), 3.m()));
+        let axis = Axis::<3>::new(point!(1.m(), 0.m(), 0.m()), dir!(0, 1, 1));
+        let transform = Transform::translation(point!(1.m(), 2.m(), 3.m()))
+            .then(Transform::rotation(axis, 30.deg()));
+        assert_eq!(
+            part.transformed(transform),
+            part.move_by(1.m(), 2.m(), 3.m())
+                .rotate_around(axis, 30.deg())
+        );
+    }
+
+    #[test]
+    fn mirror() {
+        let part = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+        assert_eq!(
+            part.transformed(Transform::mirror(Plane::yz())),
+            Cuboid::from_corners(point!(0, 0, 0), point!(-1.m(), 1.m(), 1.m()))
+        );
+    }
+
+    #[test]
+    fn scale() {
+        let part = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(
+            part.transformed(Transform::scale(point!(0, 0, 0), 2.)),
+            part.scale(2.)
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            Part::empty().transformed(Transform::translation(point!(1.m(), 1.m(), 1.m()))),
+            Part::empty()
+        );
+    }
+
+    #[test]
+    fn try_transformed_empty() {
+        assert_eq!(
+            Part::empty().try_transformed(Transform::identity()),
+            Err(Error::EmptyPart)
+        );
+    }
+
+    #[test]
+    fn scale_by_zero() {
+        let part = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let transform = Transform::scale(point!(0, 0, 0), 0.);
+        assert_eq!(part.transformed(transform), Part::empty());
+        assert_eq!(
+            part.try_transformed(transform),
+            Err(Error::SingularTransform)
+        );
+    }
+}
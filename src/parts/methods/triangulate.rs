@@ -0,0 +1,102 @@
+use crate::{Angle, Error, IndexedMesh, Length, Part, RenderMesh};
+
+impl Part {
+    /// Return the triangulated surface of this `Part` as an `IndexedMesh`.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength};
+    ///
+    /// let mesh = Cube::from_size(1.m()).triangulate().unwrap();
+    /// assert_eq!(mesh.points().len(), 8);
+    /// assert_eq!(mesh.indices().len(), 12);
+    /// ```
+    pub fn triangulate(&self) -> Result<IndexedMesh, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+        let mesh = RenderMesh::try_from(self.clone())?;
+        Ok(IndexedMesh::from_render_mesh(&mesh))
+    }
+
+    /// Return the triangulated surface of this `Part` as an `IndexedMesh` with a specified
+    /// tolerance.
+    ///
+    /// Smaller tolerances lead to higher precision in rounded shapes, but also more triangles.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned.
+    pub fn triangulate_with_tolerance(&self, tolerance: Length) -> Result<IndexedMesh, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+        let mesh = RenderMesh::try_from((self.clone(), tolerance))?;
+        Ok(IndexedMesh::from_render_mesh(&mesh))
+    }
+
+    /// Return the triangulated surface of this `Part` as an `IndexedMesh` with a specified linear
+    /// and angular tolerance.
+    ///
+    /// The linear tolerance limits the distance between the mesh and the real surface. The
+    /// angular tolerance limits the angle between the normals of adjacent triangles, which
+    /// mainly affects the quality of small curved surfaces.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{IntoAngle, IntoLength, Sphere};
+    ///
+    /// let sphere = Sphere::from_radius(1.m());
+    /// let coarse = sphere.triangulate_with_params(1.m(), 30.deg()).unwrap();
+    /// let fine = sphere.triangulate_with_params(1.m(), 5.deg()).unwrap();
+    /// assert!(fine.indices().len() > coarse.indices().len());
+    /// ```
+    pub fn triangulate_with_params(
+        &self,
+        linear: Length,
+        angular: Angle,
+    ) -> Result<IndexedMesh, Error> {
+        if self.inner.is_none() {
+            return Err(Error::EmptyPart);
+        }
+        let mesh = RenderMesh::try_from((self.clone(), linear, angular))?;
+        Ok(IndexedMesh::from_render_mesh(&mesh))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cylinder, Error, IndexedMesh, IntoAngle, IntoLength, Part, RenderMesh, Sphere};
+
+    #[test]
+    fn render_and_indexed_mesh_from_one_triangulation() {
+        let part = Sphere::from_radius(1.m()).add(&Cylinder::from_radius(0.5.m(), 3.m()));
+        let render_mesh = RenderMesh::try_from(part.clone()).unwrap();
+        let indexed_mesh = IndexedMesh::from_render_mesh(&render_mesh);
+
+        assert_eq!(indexed_mesh.indices().len(), render_mesh.indices().len());
+        assert!(indexed_mesh.points().len() < render_mesh.points().len());
+        assert_eq!(part.triangulate(), Ok(indexed_mesh));
+    }
+
+    #[test]
+    fn finer_tolerance_has_more_triangles() {
+        let sphere = Sphere::from_radius(1.m());
+        let coarse = sphere.triangulate_with_tolerance(10.cm()).unwrap();
+        let fine = sphere.triangulate_with_tolerance(1.mm()).unwrap();
+        assert!(fine.indices().len() > coarse.indices().len());
+    }
+
+    #[test]
+    fn finer_angular_tolerance_has_more_triangles() {
+        let sphere = Sphere::from_radius(1.m());
+        let coarse = sphere.triangulate_with_params(1.m(), 0.5.rad()).unwrap();
+        let fine = sphere.triangulate_with_params(1.m(), 0.1.rad()).unwrap();
+        assert!(fine.indices().len() > coarse.indices().len());
+    }
+
+    #[test]
+    fn triangulate_empty() {
+        assert_eq!(Part::empty().triangulate(), Err(Error::EmptyPart));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Part;
+
+impl Part {
+    /// Merge multiple `Part`s into one.
+    ///
+    /// The parts are merged pairwise in a balanced order, which is faster than chaining
+    /// `Part::add()` calls for many parts. An empty slice results in an empty `Part`.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Part, point};
+    ///
+    /// let cuboid1 = Cuboid::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 1.m()));
+    /// let cuboid2 = Cuboid::from_corners(point!(1.m(), 0.m(), 0.m()), point!(2.m(), 1.m(), 1.m()));
+    /// let cuboid3 = Cuboid::from_corners(point!(2.m(), 0.m(), 0.m()), point!(3.m(), 1.m(), 1.m()));
+    /// assert_eq!(
+    ///     Part::union_all(&[cuboid1, cuboid2, cuboid3]),
+    ///     Cuboid::from_corners(point!(0, 0, 0), point!(3.m(), 1.m(), 1.m()))
+    /// );
+    /// assert_eq!(Part::union_all(&[]), Part::empty());
+    /// ```
+    pub fn union_all(parts: &[Self]) -> Self {
+        match parts.len() {
+            0 => Self::empty(),
+            1 => parts[0].clone(),
+            _ => {
+                let (first_half, second_half) = parts.split_at(parts.len() / 2);
+                Self::union_all(first_half).add(&Self::union_all(second_half))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, IntoLength, Part, Sphere, point};
+
+    #[test]
+    fn union_all_matches_chained_add() {
+        let a = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        let b = Sphere::from_radius(0.7.m()).move_to(point!(1.m(), 0.m(), 0.m()));
+        let c = Cuboid::from_dim(3.m(), 0.2.m(), 0.2.m());
+        assert_eq!(
+            Part::union_all(&[a.clone(), b.clone(), c.clone()]),
+            a.add(&b).add(&c)
+        );
+    }
+
+    #[test]
+    fn union_all_single() {
+        let a = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(Part::union_all(std::slice::from_ref(&a)), a);
+    }
+
+    #[test]
+    fn union_all_with_empty_parts() {
+        let a = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+        assert_eq!(
+            Part::union_all(&[Part::empty(), a.clone(), Part::empty()]),
+            a
+        );
+    }
+
+    #[test]
+    fn union_all_empty_slice() {
+        assert_eq!(Part::union_all(&[]), Part::empty());
+    }
+}
@@ -1,29 +1,92 @@
-use opencascade_sys::ffi;
 use uom::si::f64::Volume;
 use uom::si::volume::cubic_meter;
 
-use crate::Part;
+use crate::{Error, Part};
 
 impl Part {
     /// Return the volume occupied by this `Part` in cubic meters.
     ///
+    /// An empty `Part` has a volume of zero.
+    ///
     /// ```rust
-    /// use anvil::{Cuboid, IntoLength};
+    /// use anvil::{Cuboid, IntoLength, Part};
     /// use uom::si::volume::cubic_meter;
     /// use uom::si::f64::Volume;
     /// use approx::assert_relative_eq;
     ///
     /// let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
     /// assert_relative_eq!(cuboid.volume().value, Volume::new::<cubic_meter>(1.).value);
+    /// assert_eq!(Part::empty().volume(), Volume::new::<cubic_meter>(0.));
     /// ```
     pub fn volume(&self) -> Volume {
-        match &self.inner {
-            Some(inner) => {
-                let mut gprops = ffi::GProp_GProps_ctor();
-                ffi::BRepGProp_VolumeProperties(inner, gprops.pin_mut());
-                Volume::new::<cubic_meter>(gprops.Mass())
-            }
+        match self.mass_properties() {
+            Some(properties) => properties.volume,
             None => Volume::new::<cubic_meter>(0.),
         }
     }
+
+    /// Fallible version of `Part::volume`.
+    ///
+    /// Returns `Error::EmptyPart` for an empty `Part`, which distinguishes it from a `Part` that is
+    /// not empty but has no volume, like an open shell.
+    ///
+    /// ```rust
+    /// use anvil::{Cuboid, Error, IntoLength, Part};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let cuboid = Cuboid::from_dim(1.m(), 1.m(), 1.m());
+    /// assert_relative_eq!(cuboid.try_volume().unwrap().value, 1.);
+    /// assert_eq!(Part::empty().try_volume(), Err(Error::EmptyPart));
+    /// ```
+    pub fn try_volume(&self) -> Result<Volume, Error> {
+        match self.mass_properties() {
+            Some(properties) => Ok(properties.volume),
+            None => Err(Error::EmptyPart),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Error, IntoLength, Part, Path, Plane, dir, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn volume_is_memoized() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        assert!(cuboid.properties.get().is_none());
+
+        let volume = cuboid.volume();
+        assert_relative_eq!(volume.value, 6.);
+        assert!(cuboid.properties.get().is_some());
+        assert_eq!(cuboid.volume(), volume);
+    }
+
+    #[test]
+    fn memoized_properties_survive_clone() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        cuboid.volume();
+        assert_eq!(cuboid.clone().properties.get(), cuboid.properties.get());
+    }
+
+    #[test]
+    fn transformed_part_is_not_memoized() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        cuboid.volume();
+        assert!(cuboid.scale(2.).properties.get().is_none());
+    }
+
+    #[test]
+    fn try_volume_empty() {
+        assert_eq!(Part::empty().volume().value, 0.);
+        assert_eq!(Part::empty().try_volume(), Err(Error::EmptyPart));
+    }
+
+    #[test]
+    fn try_volume_zero_volume_part() {
+        let path = Path::at(point!(0, 0)).line_to(point!(1.m(), 0.m()));
+        let plane = Plane::from_normal(point!(0.m(), 0.m(), 1.m()), dir!(0, 0, 1)).unwrap();
+        let band = path.loft_to(Plane::xy(), &path, plane).unwrap();
+        assert_relative_eq!(band.try_volume().unwrap().value, 0., epsilon = 1e-9);
+    }
 }
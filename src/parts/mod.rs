@@ -1,5 +1,7 @@
 mod methods;
 mod part;
 pub mod primitives;
+mod topology;
 
 pub use part::Part;
+pub use topology::Topology;
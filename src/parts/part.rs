@@ -1,13 +1,104 @@
+use std::cell::OnceCell;
+use std::rc::Rc;
+
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
+use uom::si::f64::Volume;
+use uom::si::length::meter;
+use uom::si::volume::cubic_meter;
+
+use crate::{Length, Point};
 
 /// A 3D object in space.
+///
+/// Operations on an empty `Part` return an empty `Part` again. Their fallible `try_` versions
+/// return `Error::EmptyPart` instead.
 pub struct Part {
-    pub(crate) inner: Option<UniquePtr<ffi::TopoDS_Shape>>,
+    /// The underlying OCCT shape, shared between clones as `Part`s are immutable.
+    pub(crate) inner: Option<Rc<UniquePtr<ffi::TopoDS_Shape>>>,
+    /// Memoization of the mass properties, which never change as `Part`s are immutable.
+    pub(crate) properties: OnceCell<MassProperties>,
 }
 impl Part {
     pub(crate) fn from_occt(part: &ffi::TopoDS_Shape) -> Self {
         let inner = ffi::TopoDS_Shape_to_owned(part);
-        Self { inner: Some(inner) }
+        Self {
+            inner: Some(Rc::new(inner)),
+            properties: OnceCell::new(),
+        }
+    }
+
+    /// Construct a `Part` from a shape that was created with `opencascade-sys` directly.
+    ///
+    /// This allows integrating shapes from OCCT operations that anvil does not wrap. A null
+    /// pointer results in an empty `Part`.
+    pub fn from_shape(shape: UniquePtr<ffi::TopoDS_Shape>) -> Self {
+        if shape.is_null() {
+            return Self::empty();
+        }
+        Self {
+            inner: Some(Rc::new(shape)),
+            properties: OnceCell::new(),
+        }
+    }
+
+    /// Return the underlying OCCT shape of this `Part` or `None` if it is empty.
+    ///
+    /// This allows passing the `Part` to `opencascade-sys` functions that anvil does not wrap.
+    pub fn as_shape(&self) -> Option<&ffi::TopoDS_Shape> {
+        self.inner.as_deref().map(|inner| &**inner)
+    }
+
+    /// Return the mass properties of this `Part` or `None` if it is empty.
+    ///
+    /// The properties are only computed on the first call.
+    pub(crate) fn mass_properties(&self) -> Option<MassProperties> {
+        let inner = self.inner.as_ref()?;
+        Some(*self.properties.get_or_init(|| {
+            let mut gprops = ffi::GProp_GProps_ctor();
+            ffi::BRepGProp_VolumeProperties(inner, gprops.pin_mut());
+            let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&gprops);
+            MassProperties {
+                volume: Volume::new::<cubic_meter>(gprops.Mass()),
+                center: Point::<3>::new([
+                    Length::new::<meter>(centre_of_mass.X()),
+                    Length::new::<meter>(centre_of_mass.Y()),
+                    Length::new::<meter>(centre_of_mass.Z()),
+                ]),
+            }
+        }))
+    }
+}
+
+/// The volume and center of mass of a `Part`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) struct MassProperties {
+    pub(crate) volume: Volume,
+    pub(crate) center: Point<3>,
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Cuboid, IntoLength};
+
+    #[test]
+    fn shape_round_trip() {
+        let cuboid = Cuboid::from_dim(1.m(), 2.m(), 3.m());
+        let shape = ffi::TopoDS_Shape_to_owned(cuboid.as_shape().unwrap());
+        let part = Part::from_shape(shape);
+        assert_relative_eq!(part.volume().value, 6., max_relative = 1e-9);
+    }
+
+    #[test]
+    fn empty_part_has_no_shape() {
+        assert!(Part::empty().as_shape().is_none());
+    }
+
+    #[test]
+    fn null_shape_is_empty() {
+        assert!(Part::from_shape(UniquePtr::null()).as_shape().is_none());
     }
 }
@@ -0,0 +1,69 @@
+use crate::{Cylinder, IntoLength, Length, Part, Sphere, core::is_zero, point};
+
+/// Builder for a capsule-shaped `Part`, i.e. a cylinder with hemispherical caps.
+///
+/// While the `Capsule` struct itself is not used, its constructor methods like
+/// `Capsule::from_radius()` can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Capsule;
+impl Capsule {
+    /// Construct a centered capsule `Part` from a radius and the height of its cylindrical section.
+    ///
+    /// The caps extend the total height of the `Part` by the radius on either end.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Capsule, IntoLength, Point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let part = Capsule::from_radius(1.m(), 2.m());
+    /// assert_eq!(part.center(), Ok(Point::<3>::origin()));
+    /// assert_relative_eq!(
+    ///     part.volume().value,
+    ///     2. * std::f64::consts::PI + 4. / 3. * std::f64::consts::PI,
+    ///     max_relative = 1e-7
+    /// );
+    /// ```
+    pub fn from_radius(radius: Length, cylinder_height: Length) -> Part {
+        if is_zero(&[radius]) {
+            return Part::empty();
+        }
+
+        let cap = Sphere::from_radius(radius);
+        Cylinder::from_radius(radius, cylinder_height)
+            .add(&cap.move_to(point!(0.m(), 0.m(), cylinder_height / 2.)))
+            .add(&cap.move_to(point!(0.m(), 0.m(), -cylinder_height / 2.)))
+    }
+    /// Construct a centered capsule `Part` from a diameter and the height of its cylindrical
+    /// section.
+    pub fn from_diameter(diameter: Length, cylinder_height: Length) -> Part {
+        Self::from_radius(diameter / 2., cylinder_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn from_radius_empty() {
+        assert_eq!(Capsule::from_radius(0.m(), 1.m()), Part::empty())
+    }
+
+    #[test]
+    fn volume_is_cylinder_plus_sphere() {
+        let capsule = Capsule::from_radius(1.m(), 3.m());
+        let expected =
+            Cylinder::from_radius(1.m(), 3.m()).volume() + Sphere::from_radius(1.m()).volume();
+        assert_relative_eq!(capsule.volume().value, expected.value, max_relative = 1e-7)
+    }
+
+    #[test]
+    fn zero_height_is_sphere() {
+        assert_eq!(
+            Capsule::from_radius(1.m(), 0.m()),
+            Sphere::from_radius(1.m())
+        )
+    }
+}
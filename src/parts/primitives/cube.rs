@@ -1,4 +1,7 @@
-use crate::{Cuboid, Length, Part};
+use approx::relative_eq;
+use uom::si::length::meter;
+
+use crate::{Cuboid, IntoLength, Length, Part, Point};
 
 /// Builder for a cubic `Part`.
 ///
@@ -23,4 +26,90 @@ impl Cube {
     pub fn from_size(size: Length) -> Part {
         Cuboid::from_dim(size, size, size)
     }
+    /// Construct a centered cubic `Part` from the length on every side in meters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cube, IntoLength};
+    ///
+    /// assert_eq!(Cube::from_m(2.), Cube::from_size(2.m()));
+    /// ```
+    pub fn from_m(size: f64) -> Part {
+        Self::from_size(size.m())
+    }
+    /// Construct a cubic `Part` from its corner locations.
+    ///
+    /// If the corners do not span the same length along every axis, up to floating point
+    /// tolerance, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cube, Cuboid, IntoLength, Part, point};
+    ///
+    /// let part = Cube::from_corners(point!(0, 0, 0), point!(2.m(), 2.m(), 2.m()));
+    /// assert_eq!(part.center(), Ok(point!(1.m(), 1.m(), 1.m())));
+    ///
+    /// let not_a_cube = Cube::from_corners(point!(0, 0, 0), point!(1.m(), 2.m(), 2.m()));
+    /// assert_eq!(not_a_cube, Part::empty());
+    /// ```
+    pub fn from_corners(corner1: Point<3>, corner2: Point<3>) -> Part {
+        let dx = (corner1.x() - corner2.x()).abs().get::<meter>();
+        let dy = (corner1.y() - corner2.y()).abs().get::<meter>();
+        let dz = (corner1.z() - corner2.z()).abs().get::<meter>();
+        if !relative_eq!(dx, dy) || !relative_eq!(dy, dz) {
+            return Part::empty();
+        }
+        Cuboid::from_corners(corner1, corner2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn from_size_equals_cuboid() {
+        assert_eq!(
+            Cube::from_size(2.m()),
+            Cuboid::from_dim(2.m(), 2.m(), 2.m())
+        )
+    }
+
+    #[test]
+    fn from_corners_equals_cuboid() {
+        let corner1 = point!(1.m(), 1.m(), 1.m());
+        let corner2 = point!(-1.m(), 3.m(), -1.m());
+        assert_eq!(
+            Cube::from_corners(corner1, corner2),
+            Cuboid::from_corners(corner1, corner2)
+        )
+    }
+
+    #[test]
+    fn from_corners_float_tolerance() {
+        let corner1 = point!(0.1.m(), 0.2.m(), 0.3.m());
+        let corner2 = point!(0.4.m(), 0.5.m(), 0.6.m());
+        assert_eq!(
+            Cube::from_corners(corner1, corner2),
+            Cuboid::from_corners(corner1, corner2)
+        )
+    }
+
+    #[test]
+    fn from_size_empty() {
+        assert_eq!(Cube::from_size(0.m()), Part::empty())
+    }
+
+    #[test]
+    fn from_corners_empty() {
+        assert_eq!(
+            Cube::from_corners(point!(0, 0, 0), point!(0, 0, 0)),
+            Part::empty()
+        );
+        assert_eq!(
+            Cube::from_corners(point!(0, 0, 0), point!(1.m(), 1.m(), 2.m())),
+            Part::empty()
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use opencascade_sys::ffi;
 use uom::si::length::meter;
 
-use crate::{Length, Part, core::is_zero};
+use crate::{Angle, Dir, IntoAngle, Length, Part, Path, Plane, Point, core::is_zero};
 
 /// Builder for a cylindrical `Part`.
 ///
@@ -39,6 +39,43 @@ impl Cylinder {
         Part::from_occt(make.pin_mut().Shape())
     }
 
+    /// Construct a cylindrical sector `Part` from a given radius, height and sweep angle.
+    ///
+    /// The sector starts at the x-axis and sweeps counterclockwise around the z-axis, which is also
+    /// the axis of the full cylinder. Like `Cylinder::from_radius()`, the `Part` is centered
+    /// vertically around the xy-plane. Angles of 360° or more result in a full cylinder.
+    ///
+    /// If the radius or height is zero or the angle is not positive, an empty `Part` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{Cylinder, IntoAngle, IntoLength};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let cylinder = Cylinder::from_radius(1.m(), 2.m());
+    /// let quarter = Cylinder::from_radius_angle(1.m(), 2.m(), 90.deg());
+    /// assert_relative_eq!(
+    ///     quarter.volume().value,
+    ///     cylinder.volume().value / 4.,
+    ///     max_relative = 1e-9
+    /// );
+    /// ```
+    pub fn from_radius_angle(radius: Length, height: Length, angle: Angle) -> Part {
+        if is_zero(&[radius, height]) || angle <= 0.deg() {
+            return Part::empty();
+        }
+        if angle >= 360.deg() {
+            return Self::from_radius(radius, height);
+        }
+
+        let arc_point = |angle: Angle| Point::<2>::origin() + Dir::from(angle) * radius.abs();
+        Path::at(Point::<2>::origin())
+            .line_to(arc_point(0.deg()))
+            .arc_points(arc_point(angle / 2.), arc_point(angle))
+            .close()
+            .extrude_symmetric(Plane::xy(), height)
+            .unwrap_or_default()
+    }
+
     /// Construct a centered cylindrical `Part` from a given diameter.
     ///
     /// ```rust
@@ -64,6 +101,7 @@ impl Cylinder {
 mod tests {
     use super::*;
     use crate::IntoLength;
+    use approx::assert_relative_eq;
 
     #[test]
     fn from_radius_empty() {
@@ -76,4 +114,57 @@ mod tests {
         assert_eq!(Cylinder::from_diameter(0.m(), 1.m()), Part::empty());
         assert_eq!(Cylinder::from_diameter(1.m(), 0.m()), Part::empty());
     }
+
+    #[test]
+    fn from_radius_angle_quarter() {
+        let cylinder = Cylinder::from_radius(2.m(), 3.m());
+        let quarter = Cylinder::from_radius_angle(2.m(), 3.m(), 90.deg());
+        assert_relative_eq!(
+            quarter.volume().value,
+            cylinder.volume().value / 4.,
+            max_relative = 1e-9
+        );
+        let center = quarter.center().unwrap();
+        assert!(center.x() > 0.m() && center.y() > 0.m());
+        assert_relative_eq!(center.z().value, 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn from_radius_angle_reflex() {
+        let cylinder = Cylinder::from_radius(1.m(), 2.m());
+        let sector = Cylinder::from_radius_angle(1.m(), 2.m(), 270.deg());
+        assert_relative_eq!(
+            sector.volume().value,
+            cylinder.volume().value * 0.75,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn from_radius_angle_full() {
+        assert_eq!(
+            Cylinder::from_radius_angle(1.m(), 2.m(), 360.deg()),
+            Cylinder::from_radius(1.m(), 2.m())
+        );
+        assert_eq!(
+            Cylinder::from_radius_angle(1.m(), 2.m(), 720.deg()),
+            Cylinder::from_radius(1.m(), 2.m())
+        );
+    }
+
+    #[test]
+    fn from_radius_angle_empty() {
+        assert_eq!(
+            Cylinder::from_radius_angle(0.m(), 1.m(), 90.deg()),
+            Part::empty()
+        );
+        assert_eq!(
+            Cylinder::from_radius_angle(1.m(), 0.m(), 90.deg()),
+            Part::empty()
+        );
+        assert_eq!(
+            Cylinder::from_radius_angle(1.m(), 1.m(), 0.deg()),
+            Part::empty()
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{IntoLength, Length, Part, Sphere};
+
+/// Builder for an ellipsoidal `Part`.
+///
+/// While the `Ellipsoid` struct itself is not used, its constructor methods like
+/// `Ellipsoid::from_radii()` can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ellipsoid;
+impl Ellipsoid {
+    /// Construct a centered ellipsoidal `Part` from its radii along the x, y and z axes.
+    ///
+    /// If any of the radii is zero or negative, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Ellipsoid, IntoLength, Point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let part = Ellipsoid::from_radii(1.m(), 2.m(), 3.m());
+    /// assert_eq!(part.center(), Ok(Point::<3>::origin()));
+    /// assert_relative_eq!(
+    ///     part.volume().value,
+    ///     4. / 3. * std::f64::consts::PI * 6.,
+    ///     max_relative = 1e-7
+    /// );
+    /// ```
+    pub fn from_radii(rx: Length, ry: Length, rz: Length) -> Part {
+        if [rx, ry, rz]
+            .iter()
+            .any(|radius| *radius <= Length::new::<meter>(0.))
+        {
+            return Part::empty();
+        }
+
+        let unit_sphere = Sphere::from_radius(1.m());
+        let mut transform = ffi::gp_GTrsf_ctor();
+        transform.pin_mut().SetValue(1, 1, rx.get::<meter>());
+        transform.pin_mut().SetValue(2, 2, ry.get::<meter>());
+        transform.pin_mut().SetValue(3, 3, rz.get::<meter>());
+
+        let mut operation = ffi::BRepBuilderAPI_GTransform_ctor(
+            unit_sphere
+                .inner
+                .as_ref()
+                .expect("unit sphere is not empty"),
+            &transform,
+            true,
+        );
+        Part::from_occt(operation.pin_mut().Shape())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn from_radii_empty() {
+        assert_eq!(Ellipsoid::from_radii(0.m(), 1.m(), 1.m()), Part::empty());
+        assert_eq!(Ellipsoid::from_radii(1.m(), 0.m(), 1.m()), Part::empty());
+        assert_eq!(Ellipsoid::from_radii(1.m(), 1.m(), 0.m()), Part::empty());
+    }
+
+    #[test]
+    fn from_radii_negative() {
+        assert_eq!(Ellipsoid::from_radii(-1.m(), 1.m(), 1.m()), Part::empty());
+        assert_eq!(Ellipsoid::from_radii(1.m(), -1.m(), 1.m()), Part::empty());
+        assert_eq!(Ellipsoid::from_radii(1.m(), 1.m(), -1.m()), Part::empty());
+    }
+
+    #[test]
+    fn volume() {
+        let part = Ellipsoid::from_radii(2.m(), 0.5.m(), 3.m());
+        assert_relative_eq!(
+            part.volume().value,
+            4. / 3. * std::f64::consts::PI * 2. * 0.5 * 3.,
+            max_relative = 1e-7
+        );
+    }
+
+    #[test]
+    fn equal_radii_is_sphere() {
+        assert_eq!(
+            Ellipsoid::from_radii(2.m(), 2.m(), 2.m()),
+            Sphere::from_radius(2.m())
+        );
+    }
+}
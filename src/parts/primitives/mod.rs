@@ -1,9 +1,15 @@
+mod capsule;
 mod cube;
 mod cuboid;
 mod cylinder;
+mod ellipsoid;
+mod pyramid;
 mod sphere;
 
+pub use capsule::Capsule;
 pub use cube::Cube;
 pub use cuboid::Cuboid;
 pub use cylinder::Cylinder;
+pub use ellipsoid::Ellipsoid;
+pub use pyramid::Pyramid;
 pub use sphere::Sphere;
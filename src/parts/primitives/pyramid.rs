@@ -0,0 +1,82 @@
+use opencascade_sys::ffi;
+use uom::si::length::meter;
+
+use crate::{Error, Part, Plane, Point, Sketch};
+
+/// Builder for a pyramidal `Part`.
+///
+/// While the `Pyramid` struct itself is not used, its constructor methods like
+/// `Pyramid::from_base()` can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pyramid;
+impl Pyramid {
+    /// Construct a `Part` by connecting the outline of a base `Sketch` to a single apex point.
+    ///
+    /// Only the outer boundary of the base is used, holes are ignored. If the apex lies on the
+    /// plane of the base, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Pyramid, Square, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let part = Pyramid::from_base(&Square::from_size(2.m()), Plane::xy(), point!(0.m(), 0.m(), 3.m()));
+    /// assert_relative_eq!(part.unwrap().volume().value, 4.);
+    /// ```
+    pub fn from_base(base: &Sketch, plane: Plane, apex: Point<3>) -> Result<Part, Error> {
+        let base_shape = base.to_occt(plane)?;
+
+        let apex_offset = apex - plane.origin();
+        let height = apex_offset.x().get::<meter>() * plane.normal().x()
+            + apex_offset.y().get::<meter>() * plane.normal().y()
+            + apex_offset.z().get::<meter>() * plane.normal().z();
+        if height.abs() < f64::EPSILON {
+            return Ok(Part::empty());
+        }
+
+        let base_wire = ffi::outer_wire(ffi::TopoDS_cast_to_face(&base_shape));
+        let mut make_apex = ffi::BRepBuilderAPI_MakeVertex_gp_Pnt(&apex.to_occt_point());
+
+        let mut loft = ffi::BRepOffsetAPI_ThruSections_ctor(true);
+        loft.pin_mut().AddWire(&base_wire);
+        loft.pin_mut().AddVertex(make_apex.pin_mut().Vertex());
+        loft.pin_mut().CheckCompatibility(false);
+
+        Ok(Part::from_occt(loft.pin_mut().Shape()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoLength, Rectangle, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn square_base_volume() {
+        let base = Rectangle::from_dim(2.m(), 3.m());
+        let pyramid = Pyramid::from_base(&base, Plane::xy(), point!(0.m(), 0.m(), 4.m())).unwrap();
+        assert_relative_eq!(
+            pyramid.volume().value,
+            base.area().value * 4. / 3.,
+            max_relative = 1e-7
+        );
+    }
+
+    #[test]
+    fn empty_base() {
+        assert_eq!(
+            Pyramid::from_base(&Sketch::empty(), Plane::xy(), point!(0.m(), 0.m(), 1.m())),
+            Err(Error::EmptySketch)
+        );
+    }
+
+    #[test]
+    fn apex_on_base_plane() {
+        let base = Rectangle::from_dim(2.m(), 2.m());
+        assert_eq!(
+            Pyramid::from_base(&base, Plane::xy(), point!(5.m(), 0.m(), 0.m())),
+            Ok(Part::empty())
+        );
+    }
+}
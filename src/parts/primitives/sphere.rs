@@ -1,5 +1,6 @@
-use crate::{Length, Part, core::is_zero};
+use crate::{Angle, Cuboid, Cylinder, IntoAngle, Length, Part, Point, core::is_zero};
 use opencascade_sys::ffi;
+use uom::si::angle::radian;
 use uom::si::length::meter;
 
 /// Builder for a spherical `Part`.
@@ -35,6 +36,64 @@ impl Sphere {
             ffi::BRepPrimAPI_MakeSphere_ctor(&axis, radius.get::<meter>(), std::f64::consts::TAU);
         Part::from_occt(make_sphere.pin_mut().Shape())
     }
+    /// Construct a partial spherical `Part`, like a hemisphere or a wedge, from a given radius.
+    ///
+    /// `phi_max` is the angle swept around the z-axis, starting at the x-axis. `theta_min` and
+    /// `theta_max` limit the latitude, measured from the xy-plane towards the positive z-axis, and
+    /// are clamped to the range of -90° to 90°. The center of the full sphere lies at the origin.
+    ///
+    /// If the radius is zero or the angles do not span any volume, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{IntoAngle, IntoLength, Sphere};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let sphere = Sphere::from_radius(1.m());
+    /// let hemisphere = Sphere::from_radius_angles(1.m(), 360.deg(), 0.deg(), 90.deg());
+    /// assert_relative_eq!(
+    ///     hemisphere.volume().value,
+    ///     sphere.volume().value / 2.,
+    ///     max_relative = 1e-9
+    /// );
+    /// ```
+    pub fn from_radius_angles(
+        radius: Length,
+        phi_max: Angle,
+        theta_min: Angle,
+        theta_max: Angle,
+    ) -> Part {
+        let theta_min = theta_min.max(-90.deg());
+        let theta_max = theta_max.min(90.deg());
+        if is_zero(&[radius]) || phi_max <= 0.deg() || theta_min >= theta_max {
+            return Part::empty();
+        }
+
+        // the cutting tools reach beyond the sphere, so none of their faces touch its surface
+        let reach = radius.abs() * 2.;
+        let mut part = Self::from_radius(radius);
+        if phi_max < 360.deg() {
+            part = part.intersect(&Cylinder::from_radius_angle(reach, reach * 2., phi_max));
+        }
+        if theta_min > -90.deg() || theta_max < 90.deg() {
+            let height = |latitude: Angle| radius.abs() * latitude.get::<radian>().sin();
+            let bottom = if theta_min > -90.deg() {
+                height(theta_min)
+            } else {
+                -reach
+            };
+            let top = if theta_max < 90.deg() {
+                height(theta_max)
+            } else {
+                reach
+            };
+            part = part.intersect(&Cuboid::from_corners(
+                Point::<3>::new([-reach, -reach, bottom]),
+                Point::<3>::new([reach, reach, top]),
+            ));
+        }
+        part
+    }
     /// Construct a centered spherical `Part` from a given diameter.
     ///
     /// # Example
@@ -59,7 +118,8 @@ impl Sphere {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IntoLength;
+    use crate::{IntoLength, point};
+    use approx::assert_relative_eq;
 
     #[test]
     fn from_radius_empty() {
@@ -70,4 +130,70 @@ mod tests {
     fn from_diameter_empty() {
         assert_eq!(Sphere::from_diameter(0.m()), Part::empty())
     }
+
+    #[test]
+    fn from_radius_angles_hemisphere() {
+        let sphere = Sphere::from_radius(2.m());
+        let hemisphere = Sphere::from_radius_angles(2.m(), 360.deg(), 0.deg(), 90.deg());
+        assert_relative_eq!(
+            hemisphere.volume().value,
+            sphere.volume().value / 2.,
+            max_relative = 1e-9
+        );
+        assert!(hemisphere.center().unwrap().z() > 0.m());
+    }
+
+    #[test]
+    fn from_radius_angles_wedge() {
+        let sphere = Sphere::from_radius(1.m());
+        let wedge = Sphere::from_radius_angles(1.m(), 90.deg(), -90.deg(), 90.deg());
+        assert_relative_eq!(
+            wedge.volume().value,
+            sphere.volume().value / 4.,
+            max_relative = 1e-9
+        );
+        let center = wedge.center().unwrap();
+        assert!(center.x() > 0.m() && center.y() > 0.m());
+    }
+
+    #[test]
+    fn from_radius_angles_band() {
+        let band = Sphere::from_radius_angles(1.m(), 360.deg(), 0.deg(), 30.deg());
+        assert_relative_eq!(
+            band.volume().value,
+            std::f64::consts::PI * (0.5 - 0.5_f64.powi(3) / 3.),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn from_radius_angles_full() {
+        assert_eq!(
+            Sphere::from_radius_angles(1.m(), 360.deg(), -90.deg(), 90.deg()),
+            Sphere::from_radius(1.m())
+        );
+        assert_relative_eq!(
+            Sphere::from_radius_angles(1.m(), 360.deg(), -120.deg(), 120.deg())
+                .center()
+                .unwrap(),
+            point!(0, 0, 0),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn from_radius_angles_empty() {
+        assert_eq!(
+            Sphere::from_radius_angles(0.m(), 360.deg(), 0.deg(), 90.deg()),
+            Part::empty()
+        );
+        assert_eq!(
+            Sphere::from_radius_angles(1.m(), 0.deg(), 0.deg(), 90.deg()),
+            Part::empty()
+        );
+        assert_eq!(
+            Sphere::from_radius_angles(1.m(), 360.deg(), 30.deg(), 10.deg()),
+            Part::empty()
+        );
+    }
 }
@@ -0,0 +1,70 @@
+/// The number of topological entities a `Part` consists of.
+///
+/// Every entity is only counted once, even if it is shared by multiple higher order entities, e.g.
+/// an edge that borders two faces.
+///
+/// ```rust
+/// use anvil::{Cube, IntoLength, Topology};
+///
+/// assert_eq!(
+///     Cube::from_size(1.m()).topology(),
+///     Topology {
+///         solids: 1,
+///         shells: 1,
+///         faces: 6,
+///         edges: 12,
+///         vertices: 8,
+///     }
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct Topology {
+    /// The number of solids.
+    pub solids: usize,
+    /// The number of shells.
+    pub shells: usize,
+    /// The number of faces.
+    pub faces: usize,
+    /// The number of edges.
+    pub edges: usize,
+    /// The number of vertices.
+    pub vertices: usize,
+}
+impl Topology {
+    /// Return the Euler characteristic `V - E + F`.
+    ///
+    /// For a single closed solid bounded by simply connected faces this is 2. Note that seam edges
+    /// of periodic faces like cylinders count as regular edges, so a cylindrical through-hole does
+    /// not necessarily change the characteristic.
+    ///
+    /// ```rust
+    /// use anvil::{Cube, IntoLength};
+    ///
+    /// assert_eq!(Cube::from_size(1.m()).topology().euler_characteristic(), 2);
+    /// ```
+    pub fn euler_characteristic(&self) -> i64 {
+        self.vertices as i64 - self.edges as i64 + self.faces as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euler_characteristic_of_empty_topology() {
+        assert_eq!(Topology::default().euler_characteristic(), 0);
+    }
+
+    #[test]
+    fn euler_characteristic_of_tetrahedron() {
+        let topology = Topology {
+            solids: 1,
+            shells: 1,
+            faces: 4,
+            edges: 6,
+            vertices: 4,
+        };
+        assert_eq!(topology.euler_characteristic(), 2);
+    }
+}
@@ -1,6 +1,6 @@
 use uom::si::length::meter;
 
-use crate::{Length, Path, Point, Sketch};
+use crate::{Edge, Length, Point, Sketch};
 
 /// Builder for a circular `Sketch`.
 ///
@@ -27,16 +27,10 @@ impl Circle {
     /// );
     /// ```
     pub fn from_radius(radius: Length) -> Sketch {
-        Path::at(Point::<2>::new([radius * -1., Length::new::<meter>(0.)]))
-            .arc_points(
-                Point::<2>::new([Length::new::<meter>(0.), radius]),
-                Point::<2>::new([radius, Length::new::<meter>(0.)]),
-            )
-            .arc_points(
-                Point::<2>::new([Length::new::<meter>(0.), radius * -1.]),
-                Point::<2>::new([radius * -1., Length::new::<meter>(0.)]),
-            )
-            .close()
+        if radius <= Length::new::<meter>(0.) {
+            return Sketch::empty();
+        }
+        Sketch::from_edges(vec![Edge::Circle(Point::<2>::origin(), radius)])
     }
 
     /// Construct a centered circular `Sketch` from a given diameter.
@@ -65,12 +59,22 @@ impl Circle {
 mod tests {
     use super::*;
     use crate::IntoLength;
+    use approx::assert_relative_eq;
 
     #[test]
     fn from_radius_empty() {
         assert_eq!(Circle::from_radius(0.m()), Sketch::empty())
     }
 
+    #[test]
+    fn from_radius_area() {
+        assert_relative_eq!(
+            Circle::from_radius(2.m()).area().value,
+            4. * core::f64::consts::PI,
+            max_relative = 1e-9
+        );
+    }
+
     #[test]
     fn from_diameter_empty() {
         assert_eq!(Circle::from_diameter(0.m()), Sketch::empty())
@@ -1,7 +1,9 @@
 mod circle;
 mod rectangle;
 mod square;
+mod star;
 
 pub use circle::Circle;
 pub use rectangle::Rectangle;
 pub use square::Square;
+pub use star::Star;
@@ -1,4 +1,4 @@
-use crate::{Length, Rectangle, Sketch};
+use crate::{Length, Point, Rectangle, Sketch, point};
 
 /// Builder for a square `Sketch`.
 ///
@@ -7,7 +7,7 @@ use crate::{Length, Rectangle, Sketch};
 #[derive(Debug, PartialEq, Clone)]
 pub struct Square;
 impl Square {
-    /// Construct a centered cubic `Sketch` from the length on every side.
+    /// Construct a centered square `Sketch` from the length on every side.
     ///
     /// # Example
     /// ```rust
@@ -26,4 +26,54 @@ impl Square {
     pub fn from_size(size: Length) -> Sketch {
         Rectangle::from_dim(size, size)
     }
+
+    /// Construct a square `Sketch` centered on a point from the length on every side.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{IntoLength, Rectangle, Square, point};
+    ///
+    /// let square = Square::from_center(point!(1.m(), 2.m()), 2.m());
+    /// assert_eq!(square.center(), Ok(point!(1.m(), 2.m())));
+    /// assert_eq!(
+    ///     square,
+    ///     Rectangle::from_corners(point!(0.m(), 1.m()), point!(2.m(), 3.m()))
+    /// );
+    /// ```
+    pub fn from_center(center: Point<2>, size: Length) -> Sketch {
+        let half_diagonal = point!(size * 0.5, size * 0.5);
+        Rectangle::from_corners(center - half_diagonal, center + half_diagonal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoLength;
+
+    #[test]
+    fn from_size_empty() {
+        assert_eq!(Square::from_size(0.m()), Sketch::empty());
+    }
+
+    #[test]
+    fn from_center_empty() {
+        assert_eq!(
+            Square::from_center(point!(1.m(), 2.m()), 0.m()),
+            Sketch::empty()
+        );
+    }
+
+    #[test]
+    fn from_size_equals_rectangle() {
+        assert_eq!(Square::from_size(2.m()), Rectangle::from_dim(2.m(), 2.m()));
+    }
+
+    #[test]
+    fn from_center_at_origin_equals_from_size() {
+        assert_eq!(
+            Square::from_center(point!(0, 0), 2.m()),
+            Square::from_size(2.m())
+        );
+    }
 }
@@ -0,0 +1,79 @@
+use uom::si::length::meter;
+
+use crate::{Angle, Dir, IntoAngle, Length, Point, Sketch};
+
+/// Builder for a star-shaped `Sketch`.
+///
+/// While the `Star` struct itself is not used, its constructor methods like `Star::from_radii()`
+/// can be used to build this primitive `Sketch`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Star;
+impl Star {
+    /// Construct a centered star `Sketch` from the number of its points and two radii.
+    ///
+    /// The tips of the star lie on the outer radius and the notches between them on the inner
+    /// radius. The first tip points in positive y-direction. If the star has fewer than two points
+    /// or one of the radii is not positive, an empty `Sketch` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{IntoLength, Star, point};
+    ///
+    /// let star = Star::from_radii(5, 2.m(), 1.m());
+    /// assert!(star.area().value < f64::consts::PI * 4.);
+    /// assert!(star.area().value > f64::consts::PI * 1.);
+    /// ```
+    pub fn from_radii(points: u8, outer: Length, inner: Length) -> Sketch {
+        let zero = Length::new::<meter>(0.);
+        if points < 2 || outer <= zero || inner <= zero {
+            return Sketch::empty();
+        }
+        let step = Angle::HALF_TURN / f64::from(points);
+        let vertices: Vec<Point<2>> = (0..2 * u16::from(points))
+            .map(|i| {
+                let radius = if i % 2 == 0 { outer } else { inner };
+                Dir::from(90.deg() + step * f64::from(i)) * radius
+            })
+            .collect();
+        Sketch::polygon(&vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64;
+
+    use super::*;
+    use crate::{IntoLength, Plane, point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn too_few_points() {
+        assert_eq!(Star::from_radii(0, 2.m(), 1.m()), Sketch::empty());
+        assert_eq!(Star::from_radii(1, 2.m(), 1.m()), Sketch::empty());
+    }
+
+    #[test]
+    fn zero_radius() {
+        assert_eq!(Star::from_radii(5, 0.m(), 1.m()), Sketch::empty());
+        assert_eq!(Star::from_radii(5, 2.m(), 0.m()), Sketch::empty());
+    }
+
+    #[test]
+    fn five_points_area() {
+        let area = Star::from_radii(5, 2.m(), 1.m()).area().value;
+        assert!(area < f64::consts::PI * 4.);
+        assert!(area > f64::consts::PI * 1.);
+        // 10 triangles between the center and neighboring vertices
+        assert_relative_eq!(area, 10. * 0.5 * 2. * 1. * 36_f64.to_radians().sin());
+    }
+
+    #[test]
+    fn five_points_extrude() {
+        let star = Star::from_radii(5, 2.m(), 1.m());
+        let part = star.extrude(Plane::xy(), 1.m()).unwrap();
+        assert_relative_eq!(part.volume().value, star.area().value, max_relative = 1e-9);
+        assert_eq!(star.center(), Ok(point!(0, 0)));
+    }
+}
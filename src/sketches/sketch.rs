@@ -1,3 +1,5 @@
+use std::cell::OnceCell;
+use std::fmt;
 use std::vec;
 
 use cxx::UniquePtr;
@@ -7,11 +9,17 @@ use uom::si::area::square_meter;
 use uom::si::f64::Area;
 use uom::si::length::meter;
 
-use crate::{Angle, Axis, Edge, Error, Face, IntoAngle, IntoLength, Length, Part, Plane, Point};
+use crate::{
+    Angle, Axis, Dir, Edge, Error, Face, IntoAngle, IntoLength, Length, Part, Plane, Point,
+    Transform, dir,
+};
 
 /// A closed shape in 2D space.
+///
+/// A `Sketch` caches the OCCT geometry it is built into and can therefore, like a `Part`, not be
+/// used from multiple threads.
 #[derive(Debug, Clone)]
-pub struct Sketch(Vec<SketchAction>);
+pub struct Sketch(Vec<SketchAction>, OcctCache);
 impl Sketch {
     /// Construct an empty `Sketch` which can be used for merging with other sketches.
     ///
@@ -24,16 +32,75 @@ impl Sketch {
     /// assert_eq!(sketch.area(), Area::new::<square_meter>(0.));
     /// ```
     pub fn empty() -> Self {
-        Self(vec![])
+        Self::from_actions(vec![])
+    }
+
+    /// Construct a polygonal `Sketch` by connecting a list of points with straight lines.
+    ///
+    /// The last point is connected back to the first one. If fewer than three points are given,
+    /// an empty `Sketch` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Rectangle, Sketch, point};
+    ///
+    /// let square = Sketch::polygon(&[
+    ///     point!(0, 0),
+    ///     point!(1.m(), 0.m()),
+    ///     point!(1.m(), 1.m()),
+    ///     point!(0.m(), 1.m()),
+    /// ]);
+    /// assert_eq!(square, Rectangle::from_corners(point!(0, 0), point!(1.m(), 1.m())));
+    /// ```
+    pub fn polygon(points: &[Point<2>]) -> Self {
+        if points.len() < 3 {
+            return Self::empty();
+        }
+        Self::from_edges(
+            points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .map(|(start, end)| Edge::Line(*start, *end))
+                .collect(),
+        )
     }
 
     /// Return true if this `Sketch` is empty.
+    ///
+    /// Sketches whose actions can not produce any geometry are recognized without building them.
+    /// All others, e.g. a `Sketch` subtracted from itself, are checked by building their shape.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Rectangle, Sketch};
+    ///
+    /// assert!(Sketch::empty().is_empty());
+    /// assert!(!Rectangle::from_dim(1.m(), 1.m()).is_empty());
+    /// ```
     pub fn is_empty(&self) -> bool {
-        self.to_occt(Plane::xy()).is_err()
+        self.has_no_geometry() || self.to_occt(Plane::xy()).is_err()
+    }
+
+    /// Return true if the actions of this `Sketch` can not produce any geometry.
+    ///
+    /// This only inspects the actions, so a `Sketch` for which this is false can still turn out to
+    /// be empty once it is built.
+    fn has_no_geometry(&self) -> bool {
+        self.0.iter().fold(true, |empty, action| match action {
+            SketchAction::Add(other) => empty && other.has_no_geometry(),
+            SketchAction::AddEdges(edges) => edges.iter().all(Edge::is_degenerate),
+            SketchAction::Intersect(other) => empty || other.has_no_geometry(),
+            SketchAction::MoveTo(_)
+            | SketchAction::RotateAround(_, _)
+            | SketchAction::Scale(_)
+            | SketchAction::Subtract(_)
+            | SketchAction::Transform(_) => empty,
+        })
     }
 
     /// Return the area occupied by this `Sketch` in square meters.
     ///
+    /// The area is measured on `Plane::xy()`. As the area does not depend on the plane, this is
+    /// equivalent to `Sketch::area_on()` with any other `Plane`.
+    ///
     /// ```rust
     /// use anvil::{Rectangle, IntoLength};
     /// use uom::si::f64::Area;
@@ -44,12 +111,27 @@ impl Sketch {
     /// assert_relative_eq!(sketch.area().value, Area::new::<square_meter>(6.).value)
     /// ```
     pub fn area(&self) -> Area {
-        match self.to_occt(Plane::xy()) {
+        self.area_on(Plane::xy())
+    }
+    /// Return the area occupied by this `Sketch` in square meters when it is placed on a `Plane`.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let sketch = Rectangle::from_dim(2.m(), 3.m());
+    /// assert_relative_eq!(sketch.area_on(Plane::yz()).value, 6.)
+    /// ```
+    pub fn area_on(&self, plane: Plane) -> Area {
+        match self.to_occt(plane) {
             Ok(occt) => occt_area(&occt),
             Err(_) => Area::new::<square_meter>(0.),
         }
     }
-    /// Return the center of mass of the `Sketch`.
+    /// Return the center of mass of the `Sketch` in its local coordinates.
+    ///
+    /// The center is computed on `Plane::xy()`. Use `Sketch::center_on()` to get the center in
+    /// global coordinates after placing the `Sketch` on a `Plane`.
     ///
     /// If the `Sketch` is empty, an `Err(Error::EmptySketch)` is returned.
     ///
@@ -63,10 +145,60 @@ impl Sketch {
     /// assert_eq!(Sketch::empty().center(), Err(Error::EmptySketch));
     /// ```
     pub fn center(&self) -> Result<Point<2>, Error> {
-        let occt = self.to_occt(Plane::xy())?;
-        let point_3d = occt_center(&occt);
+        let point_3d = self.center_on(Plane::xy())?;
         Ok(Point::<2>::new([point_3d.x(), point_3d.y()]))
     }
+    /// Return the center of mass of the `Sketch` in global coordinates when it is placed on a
+    /// `Plane`.
+    ///
+    /// If the `Sketch` is empty, an `Err(Error::EmptySketch)` is returned.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rect = Rectangle::from_dim(1.m(), 2.m()).move_to(point!(3.m(), 4.m()));
+    /// assert_relative_eq!(rect.center_on(Plane::xy()).unwrap(), point!(3.m(), 4.m(), 0.m()));
+    /// assert_relative_eq!(rect.center_on(Plane::xz()).unwrap(), point!(3.m(), 0.m(), 4.m()));
+    /// ```
+    pub fn center_on(&self, plane: Plane) -> Result<Point<3>, Error> {
+        let occt = self.to_occt(plane)?;
+        Ok(occt_center(&occt))
+    }
+
+    /// Return true if a `Point` lies inside this `Sketch`.
+    ///
+    /// Points on the boundary of the `Sketch` are considered to be contained. An empty `Sketch`
+    /// does not contain any point.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Rectangle, point};
+    ///
+    /// let rect = Rectangle::from_dim(2.m(), 2.m());
+    /// assert!(rect.contains(point!(0, 0)));
+    /// assert!(rect.contains(point!(1.m(), 0.m())));
+    /// assert!(!rect.contains(point!(5.m(), 5.m())));
+    /// ```
+    pub fn contains(&self, point: Point<2>) -> bool {
+        let Ok(occt) = self.to_occt(Plane::xy()) else {
+            return false;
+        };
+        let occt_point = point.to_3d(Plane::xy()).to_occt_point();
+
+        let mut explorer = ffi::TopExp_Explorer_ctor(&occt, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+        while explorer.More() {
+            let face = ffi::TopoDS_cast_to_face(explorer.Current());
+            let classifier = ffi::BRepClass_FaceClassifier_ctor(face, &occt_point, 1e-7);
+            if matches!(
+                classifier.State(),
+                ffi::TopAbs_State::TopAbs_IN | ffi::TopAbs_State::TopAbs_ON
+            ) {
+                return true;
+            }
+            explorer.pin_mut().Next();
+        }
+        false
+    }
 
     /// Merge this `Sketch` with another.
     ///
@@ -83,11 +215,14 @@ impl Sketch {
     pub fn add(&self, other: &Self) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::Add(other.clone()));
-        Self(new_actions)
+        Self::from_actions(new_actions)
     }
 
     /// Create multiple instances of the `Sketch` spaced evenly around a point.
     ///
+    /// The original `Sketch` counts as the first instance. If the number of instances is zero, the
+    /// `Sketch` is returned unchanged.
+    ///
     /// ```rust
     /// use anvil::{IntoAngle, IntoLength, Rectangle, point};
     ///
@@ -101,12 +236,13 @@ impl Sketch {
     /// )
     /// ```
     pub fn circular_pattern(&self, around: Point<2>, instances: u8) -> Self {
+        if instances == 0 {
+            return self.clone();
+        }
         let angle_step = 360.deg() / instances as f64;
         let mut new_shape = self.clone();
-        let mut angle = 0.rad();
-        for _ in 0..instances {
-            new_shape = new_shape.add(&self.rotate_around(around, angle));
-            angle += angle_step;
+        for i in 1..instances {
+            new_shape = new_shape.add(&self.rotate_around(around, angle_step * i as f64));
         }
         new_shape
     }
@@ -125,7 +261,7 @@ impl Sketch {
     pub fn intersect(&self, other: &Self) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::Intersect(other.clone()));
-        Self(new_actions)
+        Self::from_actions(new_actions)
     }
 
     /// Create multiple instances of the `Sketch` spaced evenly until a point.
@@ -162,6 +298,32 @@ impl Sketch {
         }
         new_part
     }
+    /// Create multiple instances of the `Sketch` spaced evenly and centered on the original.
+    ///
+    /// Consecutive instances are offset by `spacing`. With an odd number of instances, one of them
+    /// is located at the original position. With an even number, the original position lies
+    /// halfway between the two innermost instances. A count of 0 results in an empty `Sketch`.
+    ///
+    /// ```rust
+    /// use anvil::{Rectangle, IntoLength, point};
+    ///
+    /// let rect = Rectangle::from_dim(1.m(), 1.m());
+    /// assert_eq!(
+    ///     rect.linear_pattern_symmetric(point!(2.m(), 0.m()), 3),
+    ///     rect.move_to(point!(-2.m(), 0.m()))
+    ///         .add(&rect)
+    ///         .add(&rect.move_to(point!(2.m(), 0.m())))
+    /// )
+    /// ```
+    pub fn linear_pattern_symmetric(&self, spacing: Point<2>, count: u8) -> Self {
+        let mut new_sketch = Self::empty();
+        for i in 0..count {
+            let offset = spacing * (i as f64 - (count as f64 - 1.) / 2.);
+            new_sketch = new_sketch.add(&self.move_by(offset.x(), offset.y()));
+        }
+        new_sketch
+    }
+
     /// Return a clone of this `Sketch` moved by a specified amount in each axis.
     ///
     /// ```rust
@@ -196,7 +358,7 @@ impl Sketch {
     pub fn move_to(&self, loc: Point<2>) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::MoveTo(loc));
-        Self(new_actions)
+        Self::from_actions(new_actions)
     }
     /// Return a clone of this `Sketch` rotated around its center.
     ///
@@ -217,6 +379,46 @@ impl Sketch {
             Err(_) => self.clone(),
         }
     }
+    /// Return a clone of this `Sketch` mirrored about the horizontal axis through its center.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Path, point};
+    ///
+    /// let triangle = Path::at(point!(0, 0))
+    ///     .line_to(point!(2.m(), 0.m()))
+    ///     .line_to(point!(0.m(), 3.m()))
+    ///     .close();
+    /// assert_eq!(
+    ///     triangle.mirror_x(),
+    ///     Path::at(point!(0.m(), 2.m()))
+    ///         .line_to(point!(2.m(), 2.m()))
+    ///         .line_to(point!(0.m(), -1.m()))
+    ///         .close()
+    /// )
+    /// ```
+    pub fn mirror_x(&self) -> Self {
+        self.mirror_through_center(dir!(0, 1, 0))
+    }
+    /// Return a clone of this `Sketch` mirrored about the vertical axis through its center.
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Path, point};
+    ///
+    /// let triangle = Path::at(point!(0, 0))
+    ///     .line_to(point!(3.m(), 0.m()))
+    ///     .line_to(point!(0.m(), 2.m()))
+    ///     .close();
+    /// assert_eq!(
+    ///     triangle.mirror_y(),
+    ///     Path::at(point!(2.m(), 0.m()))
+    ///         .line_to(point!(-1.m(), 0.m()))
+    ///         .line_to(point!(2.m(), 2.m()))
+    ///         .close()
+    /// )
+    /// ```
+    pub fn mirror_y(&self) -> Self {
+        self.mirror_through_center(dir!(1, 0, 0))
+    }
     /// Return a clone of this `Sketch` rotated around its center.
     ///
     /// Positive angle values result in a counter-clockwise rotation.
@@ -233,7 +435,7 @@ impl Sketch {
     pub fn rotate_around(&self, point: Point<2>, angle: Angle) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::RotateAround(point, angle));
-        Self(new_actions)
+        Self::from_actions(new_actions)
     }
     /// Return a clone of this `Sketch` with the size scaled by a factor.
     ///
@@ -250,7 +452,29 @@ impl Sketch {
     pub fn scale(&self, factor: f64) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::Scale(factor));
-        Self(new_actions)
+        Self::from_actions(new_actions)
+    }
+    /// Return a clone of this `Sketch` with a `Transform` applied.
+    ///
+    /// The `Transform` is interpreted in the local coordinates of the `Sketch`, where it lies in
+    /// the xy-plane. Transforms that move the `Sketch` out of that plane are not supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{IntoAngle, IntoLength, Axis, Rectangle, Transform, point};
+    ///
+    /// let rect = Rectangle::from_corners(point!(0, 0), point!(2.m(), 1.m()));
+    /// let transform = Transform::rotation(Axis::<3>::z(), 90.deg())
+    ///     .then(Transform::translation(point!(1.m(), 0.m(), 0.m())));
+    /// assert_eq!(
+    ///     rect.transformed(transform),
+    ///     Rectangle::from_corners(point!(0, 0), point!(1.m(), 2.m()))
+    /// )
+    /// ```
+    pub fn transformed(&self, transform: Transform) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Transform(transform));
+        Self::from_actions(new_actions)
     }
     /// Return a copy of this `Sketch` with the intersection of another removed.
     ///
@@ -268,11 +492,13 @@ impl Sketch {
     pub fn subtract(&self, other: &Self) -> Self {
         let mut new_actions = self.0.clone();
         new_actions.push(SketchAction::Subtract(other.clone()));
-        Self(new_actions)
+        Self::from_actions(new_actions)
     }
 
     /// Convert this `Sketch` into a `Part` by linearly extruding it.
     ///
+    /// If the edges of the `Sketch` intersect themselves, an `Error::InvalidWire` is returned.
+    ///
     /// # Example
     /// ```rust
     /// use anvil::{Cuboid, IntoLength, Rectangle, Plane, point};
@@ -299,28 +525,158 @@ impl Sketch {
         Ok(Part::from_occt(make_solid.pin_mut().Shape()))
     }
 
+    /// Convert this `Sketch` into a `Part` by linearly extruding it along a specified direction.
+    ///
+    /// Unlike `Sketch::extrude`, the direction does not have to be the normal of the plane, which
+    /// allows for oblique prisms. A direction parallel to the plane would not enclose any volume and
+    /// returns `Error::ParallelToPlane`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{IntoLength, Rectangle, Plane, dir, point};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let sketch = Rectangle::from_corners(point!(0, 0), point!(1.m(), 1.m()));
+    /// let part = sketch.extrude_along(Plane::xy(), dir!(1, 0, 1), 2_f64.sqrt().m()).unwrap();
+    /// assert_relative_eq!(part.center().unwrap(), point!(1.m(), 0.5.m(), 0.5.m()));
+    /// ```
+    pub fn extrude_along(
+        &self,
+        plane: Plane,
+        direction: Dir<3>,
+        distance: Length,
+    ) -> Result<Part, Error> {
+        if distance == Length::new::<meter>(0.) {
+            return Err(Error::EmptySketch);
+        }
+        if direction.dot(plane.normal()).abs() < 1e-9 {
+            return Err(Error::ParallelToPlane(direction));
+        }
+
+        let shape = self.to_occt(plane)?;
+        let mut make_solid = ffi::BRepPrimAPI_MakePrism_ctor(
+            &shape,
+            &(direction * distance).to_occt_vec(),
+            false,
+            true,
+        );
+
+        Ok(Part::from_occt(make_solid.pin_mut().Shape()))
+    }
+
+    /// Convert this `Sketch` into a `Part` by extruding it symmetrically in both directions.
+    ///
+    /// Half of the thickness lies on either side of the plane, so the resulting `Part` is centered
+    /// on it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, IntoLength, Rectangle, Plane, point};
+    ///
+    /// let sketch = Rectangle::from_corners(point!(0, 0), point!(1.m(), 2.m()));
+    /// assert_eq!(
+    ///     sketch.extrude_symmetric(Plane::xy(), 4.m()),
+    ///     Ok(Cuboid::from_corners(point!(0.m(), 0.m(), -2.m()), point!(1.m(), 2.m(), 2.m())))
+    /// );
+    /// ```
+    pub fn extrude_symmetric(&self, plane: Plane, thickness: Length) -> Result<Part, Error> {
+        let shifted_plane = Plane::new(
+            plane.origin() - plane.normal() * (thickness / 2.),
+            plane.x(),
+            plane.y(),
+        )?;
+        self.extrude(shifted_plane, thickness)
+    }
+
+    /// Convert this `Sketch` into a `Part` by revolving it around an axis.
+    ///
+    /// The axis should lie in the same plane as the `Sketch` and must not cross it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, Cylinder, IntoAngle, IntoLength, Plane, Rectangle, point};
+    ///
+    /// let sketch = Rectangle::from_corners(point!(0, 0), point!(1.m(), 2.m()));
+    /// assert_eq!(
+    ///     sketch.revolve(Plane::xz(), Axis::<3>::z(), 360.deg()),
+    ///     Ok(Cylinder::from_radius(1.m(), 2.m()).move_to(point!(0.m(), 0.m(), 1.m())))
+    /// );
+    /// ```
+    pub fn revolve(&self, plane: Plane, axis: Axis<3>, angle: Angle) -> Result<Part, Error> {
+        if angle == 0.rad() {
+            return Err(Error::EmptySketch);
+        }
+
+        let shape = self.to_occt(plane)?;
+        let mut make_solid = ffi::BRepPrimAPI_MakeRevol_ctor(
+            &shape,
+            &axis.to_occt_ax1(),
+            angle.get::<radian>(),
+            false,
+        );
+
+        Ok(Part::from_occt(make_solid.pin_mut().Shape()))
+    }
+
     /// Try to convert this `Sketch` into a `Face`.
-    pub fn to_face(self, plane: Plane) -> Result<Face, Error> {
+    ///
+    /// ```rust
+    /// use anvil::{IntoLength, Plane, Rectangle};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rect = Rectangle::from_dim(1.m(), 2.m());
+    /// let face = rect.to_face(Plane::xy()).unwrap();
+    /// assert_relative_eq!(face.area().value, rect.area().value);
+    /// ```
+    pub fn to_face(&self, plane: Plane) -> Result<Face, Error> {
         Ok(Face::from_occt(ffi::TopoDS_cast_to_face(
             self.to_occt(plane)?.as_ref().unwrap(),
         )))
     }
 
     pub(crate) fn from_edges(edges: Vec<Edge>) -> Self {
-        Self(vec![SketchAction::AddEdges(edges)])
+        Self::from_actions(vec![SketchAction::AddEdges(edges)])
     }
 
     pub(crate) fn to_occt(&self, plane: Plane) -> Result<UniquePtr<ffi::TopoDS_Shape>, Error> {
-        let mut occt = None;
-        for action in &self.0 {
-            occt = action.apply(occt, plane);
-        }
+        let occt = match self.1.get(plane) {
+            Some(cached) => cached,
+            None => {
+                let mut occt = None;
+                for action in &self.0 {
+                    occt = action.apply(occt, plane)?;
+                }
+                self.1.set(plane, occt.as_deref());
+                occt
+            }
+        };
 
         match occt {
             Some(face) => Ok(face),
             None => Err(Error::EmptySketch),
         }
     }
+
+    fn mirror_through_center(&self, normal: Dir<3>) -> Self {
+        match self.center() {
+            Ok(center) => {
+                let plane = Plane::from_normal(center.to_3d(Plane::xy()), normal)
+                    .expect("normal is not zero");
+                self.transformed(Transform::mirror(plane))
+            }
+            Err(_) => self.clone(),
+        }
+    }
+
+    fn from_actions(actions: Vec<SketchAction>) -> Self {
+        Self(actions, OcctCache::default())
+    }
+}
+
+impl Default for Sketch {
+    fn default() -> Self {
+        Self::empty()
+    }
 }
 
 impl PartialEq for Sketch {
@@ -356,8 +712,11 @@ fn edges_to_occt(edges: &[Edge], plane: Plane) -> Result<UniquePtr<ffi::TopoDS_S
     let wire = ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire());
 
     let make_face = ffi::BRepBuilderAPI_MakeFace_wire(&wire, false);
-    let face = make_face.Face();
-    Ok(ffi::TopoDS_Shape_to_owned(ffi::cast_face_to_shape(face)))
+    let face = ffi::cast_face_to_shape(make_face.Face());
+    if !ffi::BRepCheck_Analyzer_ctor(face).IsValid() {
+        return Err(Error::InvalidWire);
+    }
+    Ok(ffi::TopoDS_Shape_to_owned(face))
 }
 
 fn occt_area(occt: &ffi::TopoDS_Shape) -> Area {
@@ -368,16 +727,54 @@ fn occt_area(occt: &ffi::TopoDS_Shape) -> Area {
 
 fn occt_center(occt: &ffi::TopoDS_Shape) -> Point<3> {
     let mut gprops = ffi::GProp_GProps_ctor();
-    ffi::BRepGProp_VolumeProperties(occt, gprops.pin_mut());
+    ffi::BRepGProp_SurfaceProperties(occt, gprops.pin_mut());
 
     let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&gprops);
     Point::<3>::new([
         centre_of_mass.X().m(),
         centre_of_mass.Y().m(),
-        centre_of_mass.X().m(),
+        centre_of_mass.Z().m(),
     ])
 }
 
+/// Memoization of the OCCT shape that was built from the actions of a `Sketch`.
+///
+/// Sketches are immutable, so the shape only depends on the `Plane` it was built on. Only the first
+/// `Plane` is cached, sketches built on other planes are rebuilt every time. Copies of an OCCT shape
+/// share their underlying geometry, which is not safe to use from multiple threads at once. The
+/// cache therefore makes `Sketch` neither `Send` nor `Sync`, just like `Part`.
+#[derive(Default)]
+struct OcctCache(OnceCell<(Plane, Option<UniquePtr<ffi::TopoDS_Shape>>)>);
+impl OcctCache {
+    fn get(&self, plane: Plane) -> Option<Option<UniquePtr<ffi::TopoDS_Shape>>> {
+        match self.0.get() {
+            Some((cached_plane, shape)) if *cached_plane == plane => {
+                Some(shape.as_deref().map(ffi::TopoDS_Shape_to_owned))
+            }
+            _ => None,
+        }
+    }
+    fn set(&self, plane: Plane, shape: Option<&ffi::TopoDS_Shape>) {
+        // a shape built on another plane is already cached if this fails
+        let _ = self.0.set((plane, shape.map(ffi::TopoDS_Shape_to_owned)));
+    }
+}
+impl Clone for OcctCache {
+    fn clone(&self) -> Self {
+        let cache = Self::default();
+        if let Some((plane, shape)) = self.0.get() {
+            cache.set(*plane, shape.as_deref());
+        }
+        cache
+    }
+}
+
+impl fmt::Debug for OcctCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OcctCache")
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 enum SketchAction {
     Add(Sketch),
@@ -387,14 +784,15 @@ enum SketchAction {
     RotateAround(Point<2>, Angle),
     Scale(f64),
     Subtract(Sketch),
+    Transform(Transform),
 }
 impl SketchAction {
     pub fn apply(
         &self,
         sketch: Option<UniquePtr<ffi::TopoDS_Shape>>,
         plane: Plane,
-    ) -> Option<UniquePtr<ffi::TopoDS_Shape>> {
-        match self {
+    ) -> Result<Option<UniquePtr<ffi::TopoDS_Shape>>, Error> {
+        let shape = match self {
             SketchAction::Add(other) => match (sketch, other.to_occt(plane).ok()) {
                 (None, None) => None,
                 (None, Some(other)) => Some(other),
@@ -404,7 +802,11 @@ impl SketchAction {
                     Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
                 }
             },
-            SketchAction::AddEdges(edges) => edges_to_occt(edges, plane).ok(),
+            SketchAction::AddEdges(edges) => match edges_to_occt(edges, plane) {
+                Ok(shape) => Some(shape),
+                Err(Error::EmptySketch) => None,
+                Err(err) => return Err(err),
+            },
             SketchAction::Intersect(other) => match (sketch, other.to_occt(plane).ok()) {
                 (Some(self_shape), Some(other_shape)) => {
                     let mut operation = ffi::BRepAlgoAPI_Common_ctor(&self_shape, &other_shape);
@@ -463,6 +865,16 @@ impl SketchAction {
                 }
                 None => None,
             },
+            SketchAction::Transform(transform) => {
+                match (sketch, transform.in_plane(plane).to_occt()) {
+                    (Some(shape), Some(transform)) => {
+                        let mut operation =
+                            ffi::BRepBuilderAPI_Transform_ctor(&shape, &transform, true);
+                        Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
+                    }
+                    _ => None,
+                }
+            }
             SketchAction::Subtract(other) => match (sketch, other.to_occt(plane).ok()) {
                 (None, None) => None,
                 (None, Some(_)) => None,
@@ -472,17 +884,233 @@ impl SketchAction {
                     Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
                 }
             },
-        }
+        };
+        Ok(shape)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        Cuboid, Cylinder, IntoLength, Path, Point, Rectangle, point, sketches::primitives::Circle,
+        Cuboid, Cylinder, IntoLength, Path, Point, Rectangle, dir, point,
+        sketches::primitives::Circle,
     };
 
     use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn empty_is_empty_without_building() {
+        let sketch = Sketch::empty()
+            .move_to(point!(1.m(), 1.m()))
+            .add(&Sketch::from_edges(vec![Edge::Line(
+                point!(0, 0),
+                point!(0, 0),
+            )]))
+            .intersect(&Rectangle::from_dim(1.m(), 1.m()));
+        assert!(sketch.is_empty());
+        assert!(sketch.1.get(Plane::xy()).is_none());
+    }
+
+    #[test]
+    fn has_no_geometry() {
+        let rectangle = Rectangle::from_dim(1.m(), 1.m());
+        assert!(Sketch::empty().has_no_geometry());
+        assert!(rectangle.intersect(&Sketch::empty()).has_no_geometry());
+        assert!(!rectangle.has_no_geometry());
+        assert!(!Sketch::empty().add(&rectangle).has_no_geometry());
+        assert!(!rectangle.subtract(&rectangle).has_no_geometry());
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(Sketch::default().is_empty());
+    }
+
+    #[test]
+    fn mirror_y_keeps_symmetric_sketch() {
+        let rect = Rectangle::from_dim(2.m(), 1.m()).move_to(point!(3.m(), 0.m()));
+        assert_eq!(rect.mirror_y(), rect);
+        assert_eq!(rect.mirror_x(), rect);
+    }
+
+    #[test]
+    fn mirror_about_global_y_axis() {
+        let rect = Rectangle::from_dim(2.m(), 1.m()).move_to(point!(3.m(), 0.m()));
+        assert_eq!(
+            rect.transformed(Transform::mirror(Plane::yz())),
+            Rectangle::from_dim(2.m(), 1.m()).move_to(point!(-3.m(), 0.m()))
+        );
+    }
+
+    #[test]
+    fn mirror_empty() {
+        assert!(Sketch::empty().mirror_x().is_empty());
+        assert!(Sketch::empty().mirror_y().is_empty());
+    }
+
+    #[test]
+    fn circular_pattern_four_instances() {
+        let rect = Rectangle::from_corners(point!(1.m(), 1.m()), point!(2.m(), 2.m()));
+        let pattern = rect.circular_pattern(point!(0, 0), 4);
+        assert_relative_eq!(pattern.area().value, 4. * rect.area().value);
+        assert_relative_eq!(pattern.center().unwrap(), point!(0, 0));
+    }
+
+    #[test]
+    fn circular_pattern_single_instance() {
+        let rect = Rectangle::from_corners(point!(1.m(), 1.m()), point!(2.m(), 2.m()));
+        assert_eq!(rect.circular_pattern(point!(0, 0), 1), rect);
+        assert_eq!(rect.circular_pattern(point!(0, 0), 0), rect);
+    }
+
+    #[test]
+    fn linear_pattern_symmetric_odd() {
+        let rect = Rectangle::from_dim(1.m(), 1.m()).move_to(point!(5.m(), 5.m()));
+        let pattern = rect.linear_pattern_symmetric(point!(2.m(), 1.m()), 3);
+        assert_eq!(
+            pattern,
+            rect.move_by(-2.m(), -1.m())
+                .add(&rect)
+                .add(&rect.move_by(2.m(), 1.m()))
+        );
+        assert_relative_eq!(pattern.center().unwrap(), point!(5.m(), 5.m()));
+    }
+
+    #[test]
+    fn linear_pattern_symmetric_even() {
+        let rect = Rectangle::from_dim(1.m(), 1.m());
+        assert_eq!(
+            rect.linear_pattern_symmetric(point!(0.m(), 2.m()), 2),
+            rect.move_to(point!(0.m(), -1.m()))
+                .add(&rect.move_to(point!(0.m(), 1.m())))
+        );
+    }
+
+    #[test]
+    fn linear_pattern_symmetric_zero() {
+        let rect = Rectangle::from_dim(1.m(), 1.m());
+        assert!(
+            rect.linear_pattern_symmetric(point!(2.m(), 0.m()), 0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn extrude_figure_eight() {
+        let figure_eight = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 1.m()))
+            .line_to(point!(1.m(), 0.m()))
+            .line_to(point!(0.m(), 1.m()))
+            .close();
+        assert_eq!(
+            figure_eight.extrude(Plane::xy(), 1.m()),
+            Err(Error::InvalidWire)
+        );
+    }
+
+    #[test]
+    fn contains_rectangle() {
+        let rect = Rectangle::from_corners(point!(0, 0), point!(2.m(), 1.m()));
+        assert!(rect.contains(point!(1.m(), 0.5.m())));
+        assert!(!rect.contains(point!(10.m(), 10.m())));
+        assert!(!rect.contains(point!(-0.1.m(), 0.5.m())));
+    }
+
+    #[test]
+    fn contains_boundary() {
+        let rect = Rectangle::from_corners(point!(0, 0), point!(2.m(), 1.m()));
+        assert!(rect.contains(point!(2.m(), 0.5.m())));
+        assert!(rect.contains(point!(0, 0)));
+    }
+
+    #[test]
+    fn contains_hole() {
+        let ring = Circle::from_radius(2.m()).subtract(&Circle::from_radius(1.m()));
+        assert!(!ring.contains(point!(0, 0)));
+        assert!(ring.contains(point!(1.5.m(), 0.m())));
+    }
+
+    #[test]
+    fn contains_empty() {
+        assert!(!Sketch::empty().contains(point!(0, 0)));
+    }
+
+    #[test]
+    fn polygon_square() {
+        let square = Sketch::polygon(&[
+            Point::<2>::origin(),
+            point!(1.m(), 0.m()),
+            point!(1.m(), 1.m()),
+            point!(0.m(), 1.m()),
+        ]);
+        assert_eq!(
+            square,
+            Rectangle::from_corners(Point::<2>::origin(), point!(1.m(), 1.m()))
+        );
+    }
+
+    #[test]
+    fn polygon_triangle() {
+        let triangle = Sketch::polygon(&[point!(0, 0), point!(2.m(), 0.m()), point!(0.m(), 2.m())]);
+        assert_relative_eq!(triangle.area().get::<square_meter>(), 2.);
+    }
+
+    #[test]
+    fn polygon_too_few_points() {
+        assert_eq!(Sketch::polygon(&[]), Sketch::empty());
+        assert_eq!(
+            Sketch::polygon(&[point!(0, 0), point!(1.m(), 1.m())]),
+            Sketch::empty()
+        );
+    }
+
+    #[test]
+    fn to_face_keeps_sketch() {
+        let sketch = Rectangle::from_dim(1.m(), 2.m());
+        let face = sketch.to_face(Plane::xy()).unwrap();
+        assert_relative_eq!(
+            face.area().get::<square_meter>(),
+            sketch.area().get::<square_meter>(),
+        );
+        assert_eq!(
+            sketch.extrude(Plane::xy(), 3.m()),
+            Ok(Cuboid::from_corners(
+                point!(-0.5.m(), -1.m(), 0.m()),
+                point!(0.5.m(), 1.m(), 3.m())
+            ))
+        );
+    }
+
+    #[test]
+    fn area_on_is_plane_invariant() {
+        let sketch = Rectangle::from_dim(1.m(), 2.m()).move_to(point!(3.m(), 4.m()));
+        let plane = Plane::from_normal(point!(1.m(), 2.m(), 3.m()), dir!(1, 1, 1)).unwrap();
+        assert_relative_eq!(sketch.area_on(Plane::xz()).value, sketch.area().value);
+        assert_relative_eq!(sketch.area_on(plane).value, sketch.area().value);
+    }
+
+    #[test]
+    fn center_on_offset_plane() {
+        let sketch = Rectangle::from_dim(1.m(), 2.m()).move_to(point!(3.m(), 4.m()));
+        let plane = Plane::new(point!(0.m(), 0.m(), 5.m()), dir!(0, 1, 0), dir!(0, 0, 1)).unwrap();
+        assert_relative_eq!(
+            sketch.center_on(plane).unwrap(),
+            point!(0.m(), 3.m(), 9.m())
+        );
+        assert_relative_eq!(
+            sketch.center_on(Plane::xz()).unwrap(),
+            point!(3.m(), 0.m(), 4.m())
+        );
+    }
+
+    #[test]
+    fn center_on_empty() {
+        assert_eq!(
+            Sketch::empty().center_on(Plane::xz()),
+            Err(Error::EmptySketch)
+        );
+    }
 
     #[test]
     fn eq_both_rectangles() {
@@ -547,6 +1175,82 @@ mod tests {
         assert!(sketch1.intersect(&sketch2).to_occt(Plane::xy()).is_err())
     }
 
+    #[test]
+    fn extrude_along_slanted() {
+        let sketch = Rectangle::from_corners(point!(0, 0), point!(1.m(), 1.m()));
+        let part = sketch
+            .extrude_along(Plane::xy(), dir!(1, 0, 1), 2_f64.sqrt().m())
+            .unwrap();
+        assert_relative_eq!(part.volume().value, 1., epsilon = 1e-9);
+
+        let top = part.intersect(&Cuboid::from_corners(
+            point!(-5.m(), -5.m(), 0.9.m()),
+            point!(5.m(), 5.m(), 1.m()),
+        ));
+        assert_relative_eq!(top.center().unwrap(), point!(1.45.m(), 0.5.m(), 0.95.m()));
+    }
+
+    #[test]
+    fn extrude_along_parallel_to_plane() {
+        let sketch = Rectangle::from_corners(point!(0, 0), point!(1.m(), 1.m()));
+        assert_eq!(
+            sketch.extrude_along(Plane::xy(), dir!(1, 1, 0), 1.m()),
+            Err(Error::ParallelToPlane(dir!(1, 1, 0)))
+        );
+        assert_eq!(
+            sketch.extrude_along(Plane::xz(), dir!(1, 0, 1), 1.m()),
+            Err(Error::ParallelToPlane(dir!(1, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn extrude_symmetric_center_on_plane() {
+        let sketch = Rectangle::from_dim(1.m(), 1.m());
+        let plane = Plane::xz();
+        let symmetric = sketch.extrude_symmetric(plane, 2.m()).unwrap();
+        let asymmetric = sketch.extrude(plane, 2.m()).unwrap();
+        assert_relative_eq!(symmetric.center().unwrap(), point!(0, 0, 0));
+        assert_relative_eq!(asymmetric.center().unwrap(), point!(0.m(), -1.m(), 0.m()));
+    }
+
+    #[test]
+    fn repeated_area_does_not_rebuild() {
+        let sketch = Rectangle::from_dim(1.m(), 1.m())
+            .add(&Circle::from_radius(1.m()).move_to(point!(1.m(), 0.m())))
+            .subtract(&Circle::from_radius(0.5.m()));
+
+        sketch.area();
+        let cached_shape = |sketch: &Sketch| match sketch.1.0.get() {
+            Some((_, Some(shape))) => shape.as_ref().unwrap() as *const ffi::TopoDS_Shape,
+            _ => panic!("sketch was not cached"),
+        };
+        let first_build = cached_shape(&sketch);
+        for _ in 0..100 {
+            sketch.area();
+        }
+        assert_eq!(cached_shape(&sketch), first_build);
+    }
+
+    #[test]
+    fn cache_keeps_first_plane() {
+        let sketch = Sketch::empty();
+        sketch.1.set(Plane::xy(), None);
+        sketch.1.set(Plane::xz(), None);
+        assert!(sketch.1.get(Plane::xy()).is_some());
+        assert!(sketch.1.get(Plane::xz()).is_none());
+        assert!(sketch.clone().1.get(Plane::xy()).is_some());
+    }
+
+    #[test]
+    fn cache_is_plane_specific() {
+        let sketch = Rectangle::from_dim(1.m(), 2.m());
+        assert!(sketch.extrude(Plane::xy(), 1.m()).is_ok());
+        assert_eq!(
+            sketch.extrude(Plane::xz(), 3.m()),
+            Ok(Cuboid::from_dim(1.m(), 3.m(), 2.m()).move_to(point!(0.m(), -1.5.m(), 0.m())))
+        );
+    }
+
     #[test]
     fn extrude_empty_sketch() {
         let sketch = Sketch::empty();
@@ -578,6 +1282,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn bezier_quarter_circle() {
+        let k = 0.5522847498;
+        let sketch = Path::at(point!(0, 0))
+            .line_to(point!(1.m(), 0.m()))
+            .bezier_to(
+                point!(1.m(), k.m()),
+                point!(k.m(), 1.m()),
+                point!(0.m(), 1.m()),
+            )
+            .close();
+        assert_relative_eq!(
+            sketch.area().value,
+            std::f64::consts::FRAC_PI_4,
+            max_relative = 1e-3
+        );
+        assert!(sketch.extrude(Plane::xy(), 1.m()).is_ok());
+    }
+
+    #[test]
+    fn extrude_spline() {
+        let sketch = Path::at(point!(0, 0))
+            .spline_through(&[
+                point!(1.m(), 1.m()),
+                point!(2.m(), 1.5.m()),
+                point!(3.m(), 0.m()),
+            ])
+            .close();
+        assert!(sketch.area().value > 0.);
+
+        let part = sketch.extrude(Plane::xy(), 1.m()).unwrap();
+        assert_relative_eq!(
+            part.volume().value,
+            sketch.area().value,
+            max_relative = 1e-6
+        );
+    }
+
     #[test]
     fn extrude_cylinder() {
         let sketch = Circle::from_radius(1.m());